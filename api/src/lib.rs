@@ -4,10 +4,178 @@
 [github]: https://img.shields.io/badge/github-8da0cb?style=for-the-badge&labelColor=555555&logo=github
 [crates-io]: https://img.shields.io/badge/crates.io-fc8d62?style=for-the-badge&labelColor=555555&logo=rust
 [docs-rs]: https://img.shields.io/badge/docs.rs-66c2a5?style=for-the-badge&labelColor=555555&logo=docs.rs
-This library provide definitions used by [mapper](https://docs.rs/mapper-api/1.0.1/mapper_api) crate
+This library provide definitions used by [mapper](https://docs.rs/mapper) crate
+
+[Mapper] is implemented for standard containers (Option, Vec, Box, HashMap and BTreeMap values, tuples and Result)
+as soon as their elements implement [Mapper], e.g `Vec<User>` implements `Mapper<Vec<Person>>` if `User` implements `Mapper<Person>`.
+
+There is no blanket identity implementation because it would overlap with containers implementations,
+identity is instead implemented for primitive types, `String` and `&str` so containers of these types map to themselves.
+Orphan rules prevent implementing identity for types of other crates, such fields are mapped with the `direct` flag or a `with` function.
+
+`Box` being a fundamental type, `Mapper<Box<U>>` for `Box<T>` is provided here and cannot be implemented by dependent crates.
  */
 
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+/// Items used by generated code, not part of the public api
+#[doc(hidden)]
+pub mod __private {
+    pub use std::{boxed::Box, rc::Rc, sync::Arc};
+}
+
 /// Trait defining a mapper converting itself to a destination Type of T
 pub trait Mapper<T> {
     fn to(&self) -> T;
 }
+
+/// Trait defining a conversion of a field to a destination field type of Dst,
+/// used by every field of a mapping configured with the `convert` flag,
+/// conversions between types of other crates need a newtype or a `with` function because of orphan rules
+pub trait FieldConvert<Dst> {
+    fn convert(&self) -> Dst;
+}
+
+/// Fields having the same type in source and destination are cloned
+impl<T: Clone> FieldConvert<T> for T {
+    fn convert(&self) -> T {
+        self.clone()
+    }
+}
+
+macro_rules! impl_identity {
+    ($($ty:ty),*) => {
+        $(
+            impl Mapper<$ty> for $ty {
+                fn to(&self) -> $ty {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_identity!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    String
+);
+
+impl<'a> Mapper<&'a str> for &'a str {
+    fn to(&self) -> &'a str {
+        self
+    }
+}
+
+impl<T: Mapper<U>, U> Mapper<Option<U>> for Option<T> {
+    fn to(&self) -> Option<U> {
+        self.as_ref().map(Mapper::to)
+    }
+}
+
+impl<T: Mapper<U>, U, E: Mapper<F>, F> Mapper<Result<U, F>> for Result<T, E> {
+    fn to(&self) -> Result<U, F> {
+        match self {
+            Ok(value) => Ok(value.to()),
+            Err(error) => Err(error.to()),
+        }
+    }
+}
+
+impl<T: Mapper<U>, U> Mapper<Vec<U>> for Vec<T> {
+    fn to(&self) -> Vec<U> {
+        self.iter().map(Mapper::to).collect()
+    }
+}
+
+impl<T: Mapper<U>, U> Mapper<Box<U>> for Box<T> {
+    fn to(&self) -> Box<U> {
+        Box::new((**self).to())
+    }
+}
+
+impl<K, V, W, S> Mapper<HashMap<K, W, S>> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Mapper<W>,
+    S: BuildHasher + Default,
+{
+    fn to(&self) -> HashMap<K, W, S> {
+        self.iter()
+            .map(|(key, value)| (key.clone(), value.to()))
+            .collect()
+    }
+}
+
+impl<K, V, W> Mapper<BTreeMap<K, W>> for BTreeMap<K, V>
+where
+    K: Ord + Clone,
+    V: Mapper<W>,
+{
+    fn to(&self) -> BTreeMap<K, W> {
+        self.iter()
+            .map(|(key, value)| (key.clone(), value.to()))
+            .collect()
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($src:ident, $dst:ident, $index:tt)),+) => {
+        impl<$($src: Mapper<$dst>, $dst),+> Mapper<($($dst,)+)> for ($($src,)+) {
+            fn to(&self) -> ($($dst,)+) {
+                ($(self.$index.to(),)+)
+            }
+        }
+    };
+}
+
+impl_tuple!((A, A2, 0));
+impl_tuple!((A, A2, 0), (B, B2, 1));
+impl_tuple!((A, A2, 0), (B, B2, 1), (C, C2, 2));
+impl_tuple!((A, A2, 0), (B, B2, 1), (C, C2, 2), (D, D2, 3));
+impl_tuple!((A, A2, 0), (B, B2, 1), (C, C2, 2), (D, D2, 3), (E, E2, 4));
+impl_tuple!(
+    (A, A2, 0),
+    (B, B2, 1),
+    (C, C2, 2),
+    (D, D2, 3),
+    (E, E2, 4),
+    (F, F2, 5)
+);
+impl_tuple!(
+    (A, A2, 0),
+    (B, B2, 1),
+    (C, C2, 2),
+    (D, D2, 3),
+    (E, E2, 4),
+    (F, F2, 5),
+    (G, G2, 6)
+);
+impl_tuple!(
+    (A, A2, 0),
+    (B, B2, 1),
+    (C, C2, 2),
+    (D, D2, 3),
+    (E, E2, 4),
+    (F, F2, 5),
+    (G, G2, 6),
+    (H, H2, 7)
+);
@@ -1,8 +1,21 @@
 use proc_macro2::Ident;
-use syn::{DataStruct, DeriveInput, Generics, Result, TypePath};
+use quote::ToTokens;
+use syn::{DataStruct, DeriveInput, Error, Generics, Path, TypePath};
 
 use crate::attr::{
-    self, attrs::Attrs, data_type::params::Params, mapping_strategy::MappingStrategy, to::To,
+    self,
+    attrs::Attrs,
+    container::Container,
+    data_type::{mapping_table::MappingTarget, params::Params},
+    field::{self, conversion::Conversion, exclude::Exclude, target::Target, with::With},
+    mapping_strategy::MappingStrategy,
+    spanned_item::SpannedItem,
+    to::To,
+};
+
+use crate::{
+    common::{errors::Errors, ordered_set::OrderedSet},
+    valid::Validation,
 };
 
 use super::field::Field;
@@ -11,25 +24,48 @@ use super::field::Field;
 pub struct Struct<'a> {
     pub original: &'a DeriveInput,
     pub attrs: Attrs<To<'a, Params>>,
+    pub container: Container,
     pub ident: Ident,
     pub generics: &'a Generics,
     pub fields: Vec<Field<'a>>,
 }
 
 impl<'a> Struct<'a> {
-    pub fn from_syn(node: &'a DeriveInput, data: &'a DataStruct) -> Result<Self> {
-        let attrs = attr::data_type::get(node)?;
-        let fields = Field::multiple_from_syn(&data.fields)?;
+    /// Build the struct from its valid attributes, attribute errors are reported in validation
+    pub fn from_syn(
+        node: &'a DeriveInput,
+        data: &'a DataStruct,
+        validation: &mut Validation,
+    ) -> Self {
+        let mut errors = Errors::default();
+        let container = attr::container::get(node, &mut errors);
+        if let Some(error) = errors.into_error() {
+            validation.push_unparsed(error, None);
+        }
+        let attrs = attr::data_type::get(node, &container);
+        let mut fields = Field::multiple_from_syn(&data.fields, &container);
+        report_unparsed_attributes(&attrs, &fields, &container, validation);
+        resolve_wildcard_targets(&attrs, &mut fields, validation);
+        apply_mapping_tables(&attrs, &mut fields, validation);
 
-        Ok(Struct {
+        Struct {
             original: node,
             attrs,
+            container,
             ident: node.ident.clone(),
             generics: &node.generics,
             fields,
-        })
+        }
     }
 
+    /// Unmapped fields are denied for the destination by a struct attribute or by the container
+    pub fn denies_unmapped_fields(&self, destination: &TypePath) -> bool {
+        self.container.deny_unmapped.is_some()
+            || self.attrs.to.to_items.iter().any(|struct_to| {
+                struct_to.params.deny_unmapped.1
+                    && struct_to.params.destinations.contains(destination)
+            })
+    }
     pub fn has_strategy_for_destination(
         &self,
         destination: &TypePath,
@@ -46,3 +82,213 @@ impl<'a> Struct<'a> {
             })
     }
 }
+
+/// Report errors of attributes which couldn't be parsed for the destinations they target
+fn report_unparsed_attributes(
+    attrs: &Attrs<To<Params>>,
+    fields: &[Field],
+    container: &Container,
+    validation: &mut Validation,
+) {
+    let struct_destinations = get_destinations(attrs);
+    for unparsed in attrs.to.unparsed_items.iter().chain(
+        fields
+            .iter()
+            .flat_map(|field| &field.attrs.to.unparsed_items),
+    ) {
+        let mut destinations = OrderedSet::new();
+        for target in &unparsed.targets {
+            match target {
+                Target::Type(ty) => destinations.extend(
+                    field::resolve_group(ty, container).unwrap_or_else(|_| vec![ty.clone()]),
+                ),
+                Target::Wildcard(_) => destinations.extend(struct_destinations.iter().cloned()),
+            }
+        }
+        validation.push_unparsed(unparsed.error.clone(), &destinations);
+    }
+}
+
+/// Destinations declared by struct attributes
+fn get_destinations(attrs: &Attrs<To<Params>>) -> OrderedSet<TypePath> {
+    attrs
+        .to
+        .to_items
+        .iter()
+        .flat_map(|struct_to| struct_to.params.destinations.iter().cloned())
+        .collect()
+}
+
+/// Replace wildcard field attributes by one attribute by struct destination without specific field attribute,
+/// with functions are kept only for strategies of the destination
+fn resolve_wildcard_targets(
+    attrs: &Attrs<To<Params>>,
+    fields: &mut [Field],
+    validation: &mut Validation,
+) {
+    let destinations = get_destinations(attrs);
+    for field in fields {
+        let (mut wildcard_items, mut to_items): (Vec<_>, Vec<_>) =
+            field.attrs.to.to_items.drain(..).partition(|field_to| {
+                field_to
+                    .params
+                    .targets
+                    .iter()
+                    .any(|target| matches!(target, Target::Wildcard(_)))
+            });
+        if wildcard_items.len() > 1 {
+            validation.push(
+                Error::new_spanned(
+                    wildcard_items[1].original,
+                    "Cannot specify multiple wildcard field attributes",
+                ),
+                &destinations,
+            );
+            wildcard_items.truncate(1);
+        }
+        if let Some(wildcard_item) = wildcard_items.pop() {
+            if destinations.is_empty() {
+                validation.push(
+                    Error::new_spanned(
+                        &wildcard_item.params.targets[0],
+                        "Wildcard destination cannot be used on a struct without destinations",
+                    ),
+                    None,
+                );
+            }
+            let specific_destinations = to_items
+                .iter()
+                .filter_map(|field_to| field_to.params.destination.clone())
+                .collect::<OrderedSet<TypePath>>();
+            for destination in destinations
+                .iter()
+                .filter(|&destination| !specific_destinations.contains(destination))
+            {
+                let mut field_to = wildcard_item.clone();
+                field_to.params.destination = Some(destination.clone());
+                field_to
+                    .params
+                    .with
+                    .retain(|with| attrs.to.has_destination_for_strategy(destination, &with.1));
+                if field_to.params.with.is_empty() {
+                    if let Some(with) = wildcard_item.params.with.iter().next() {
+                        validation.push(Error::new_spanned(
+                            &with.0,
+                            format!(
+                                "Wildcard with function strategies ({}) don't match any strategy of destination ({}), specify the strategy with with(<strategy>)",
+                                wildcard_item
+                                    .params
+                                    .with
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<String>>()
+                                    .join(","),
+                                destination.to_token_stream()
+                            ),
+                        ), Some(destination));
+                    }
+                }
+                to_items.push(field_to);
+            }
+        }
+        field.attrs.to.to_items = to_items;
+    }
+}
+
+/// Convert struct mapping tables entries into field attributes for the attribute destinations
+fn apply_mapping_tables<'a>(
+    attrs: &Attrs<To<'a, Params>>,
+    fields: &mut [Field<'a>],
+    validation: &mut Validation,
+) {
+    let mut table_items: Vec<Vec<To<field::params::Params>>> = vec![Vec::new(); fields.len()];
+    for struct_to in &attrs.to.to_items {
+        for entry in &struct_to.params.mapping_table {
+            let (field, field_table_items) = match fields
+                .iter()
+                .zip(table_items.iter_mut())
+                .find(|(field, _)| field.member == entry.source)
+            {
+                Some(found) => found,
+                None => {
+                    validation.push(
+                        Error::new_spanned(
+                            &entry.source,
+                            format!(
+                                "Mapping table field ({}) doesn't exist in struct",
+                                entry.source.to_token_stream()
+                            ),
+                        ),
+                        &struct_to.params.destinations,
+                    );
+                    continue;
+                }
+            };
+            for destination in &struct_to.params.destinations {
+                if field_table_items
+                    .iter()
+                    .any(|field_to| field_to.params.destination.as_ref() == Some(destination))
+                {
+                    validation.push(Error::new_spanned(
+                        &entry.source,
+                        format!(
+                            "Cannot map multiple time field ({}) for destination ({}) in mapping tables",
+                            entry.source.to_token_stream(),
+                            destination.to_token_stream()
+                        ),
+                    ), Some(destination));
+                    continue;
+                }
+                if field.attrs.to.to_items.iter().any(|field_to| {
+                    field_to.params.destination.is_none()
+                        || field_to.params.destination.as_ref() == Some(destination)
+                }) {
+                    validation.push(Error::new_spanned(
+                        &entry.source,
+                        format!(
+                            "Mapping table entry of field ({}) conflicts with field attribute for destination ({})",
+                            entry.source.to_token_stream(),
+                            destination.to_token_stream()
+                        ),
+                    ), Some(destination));
+                    continue;
+                }
+                let params = match &entry.target {
+                    MappingTarget::Field { field, with } => field::params::Params {
+                        targets: vec![Target::Type(destination.clone())],
+                        destination: Some(destination.clone()),
+                        field: Some(field.clone()),
+                        with: with
+                            .iter()
+                            .map(|(with, strategy)| {
+                                SpannedItem::new(With::from(with.clone()), strategy.clone())
+                            })
+                            .collect(),
+                        exclude: Exclude::default(),
+                        strategies: OrderedSet::new(),
+                        conversion: Conversion::default(),
+                    },
+                    MappingTarget::Exclude(underscore) => field::params::Params {
+                        targets: vec![Target::Type(destination.clone())],
+                        destination: Some(destination.clone()),
+                        field: None,
+                        with: OrderedSet::new(),
+                        exclude: Exclude {
+                            path: Some(Path::from(Ident::new("exclude", underscore.span))),
+                            strategies: struct_to.params.strategies.clone(),
+                        },
+                        strategies: OrderedSet::new(),
+                        conversion: Conversion::default(),
+                    },
+                };
+                field_table_items.push(To {
+                    original: struct_to.original,
+                    params,
+                });
+            }
+        }
+    }
+    for (field, field_table_items) in fields.iter_mut().zip(table_items) {
+        field.attrs.to.to_items.extend(field_table_items);
+    }
+}
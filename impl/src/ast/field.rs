@@ -1,6 +1,7 @@
-use syn::{Fields, Index, Member, Result, Type};
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Fields, Index, Member, Type};
 
-use crate::attr::{self, attrs::Attrs, field::params::Params, to::To};
+use crate::attr::{self, attrs::Attrs, container::Container, field::params::Params, to::To};
 
 #[derive(Debug, Clone)]
 pub struct Field<'a> {
@@ -11,24 +12,31 @@ pub struct Field<'a> {
 }
 
 impl<'a> Field<'a> {
-    pub fn multiple_from_syn(fields: &'a Fields) -> Result<Vec<Self>> {
+    pub fn multiple_from_syn(fields: &'a Fields, container: &Container) -> Vec<Self> {
         fields
             .iter()
             .enumerate()
-            .map(|(i, field)| Field::from_syn(i, field))
+            .map(|(i, field)| Field::from_syn(i, field, container))
             .collect()
     }
 
-    pub fn from_syn(i: usize, node: &'a syn::Field) -> Result<Self> {
-        Ok(Field {
+    /// Span of the field identifier or of the whole field for tuple struct fields
+    pub fn span(&self) -> Span {
+        self.original
+            .ident
+            .as_ref()
+            .map_or_else(|| self.original.span(), |ident| ident.span())
+    }
+    pub fn from_syn(i: usize, node: &'a syn::Field, container: &Container) -> Self {
+        Field {
             original: node,
-            attrs: attr::field::get(node)?,
+            attrs: attr::field::get(node, container),
             member: node
                 .ident
                 .clone()
                 .map(Member::Named)
                 .unwrap_or_else(|| Member::Unnamed(Index::from(i))),
             ty: &node.ty,
-        })
+        }
     }
 }
@@ -1,14 +1,23 @@
-use crate::attr::mapping_strategy::MappingStrategy;
+use crate::attr::{
+    field::{conversion::Conversion, with::With},
+    mapping_mode::MappingMode,
+    mapping_strategy::MappingStrategy,
+};
+use proc_macro2::Span;
 use std::hash::Hash;
-use syn::{Member, Path, Type};
+use syn::{Member, Type};
 
-#[derive(Eq, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct MappingField {
     pub ty: Type,
     pub strategy: MappingStrategy,
     pub member: Member,
-    pub field: Option<Path>,
-    pub with: Option<Path>,
+    /// Span of the source field
+    pub span: Span,
+    pub field: Option<Member>,
+    pub with: Option<With>,
+    pub conversion: Conversion,
+    pub mode: MappingMode,
 }
 
 impl Hash for MappingField {
@@ -18,6 +27,7 @@ impl Hash for MappingField {
         self.member.hash(state);
     }
 }
+impl Eq for MappingField {}
 impl PartialEq for MappingField {
     fn eq(&self, other: &Self) -> bool {
         self.ty == other.ty && self.strategy == other.strategy && self.member == other.member
@@ -1,10 +1,15 @@
-use std::collections::HashSet;
+use crate::common::ordered_set::OrderedSet;
 use std::hash::Hash;
 
 use proc_macro2::Ident;
-use syn::{Member, TypePath};
+use syn::{ext::IdentExt, Member, TypePath};
 
-use crate::attr::mapping_strategy::MappingStrategy;
+use crate::{
+    attr::{
+        data_type::rename::Rename, mapping_mode::MappingMode, mapping_strategy::MappingStrategy,
+    },
+    common::ident::parse_ident,
+};
 
 use super::mapping_field::MappingField;
 
@@ -19,8 +24,13 @@ pub struct MappingTree {
     pub ident: Ident,
     pub destination: TypePath,
     pub strategy: MappingStrategy,
-    pub mapping_fields: HashSet<MappingField>,
+    pub mapping_fields: OrderedSet<MappingField>,
     pub mapping_type: Option<MappingType>,
+    pub transparent: bool,
+    /// Every source field should be mapped or excluded
+    pub deny_unmapped: bool,
+    pub mode: MappingMode,
+    pub rename: Rename,
 }
 
 impl PartialEq for MappingTree {
@@ -49,9 +59,13 @@ impl MappingTree {
         Self {
             destination,
             ident,
-            mapping_fields: HashSet::new(),
+            mapping_fields: OrderedSet::new(),
             strategy,
             mapping_type,
+            transparent: false,
+            deny_unmapped: false,
+            mode: MappingMode::default(),
+            rename: Rename::default(),
         }
     }
     pub fn remove_mapping_fields_by_member(&mut self, member: &Member) {
@@ -65,6 +79,19 @@ impl MappingTree {
             self.mapping_fields.remove(&field);
         }
     }
+    /// Destination field of a source member, explicit field wins over the renamed member
+    pub fn get_destination_field(&self, field: Option<&Member>, member: &Member) -> Option<Member> {
+        if let Some(field) = field {
+            return Some(field.clone());
+        }
+        match member {
+            Member::Named(ident) if !self.rename.is_empty() => {
+                let name = self.rename.apply(&ident.unraw().to_string()).ok()?;
+                parse_ident(&name, ident.span()).map(Member::Named)
+            }
+            _ => None,
+        }
+    }
     pub fn has_mapping_type(&self, mapping_type: &MappingType) -> bool {
         if let Some(mapping_tree_type) = &self.mapping_type {
             mapping_tree_type == mapping_type
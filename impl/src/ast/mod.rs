@@ -1,8 +1,13 @@
-use std::{collections::HashSet, fmt::Debug};
-use syn::{Data, DeriveInput, Error, Path, Result};
+use std::fmt::Debug;
+
+use crate::{common::ordered_set::OrderedSet, valid::Validation};
+use syn::{Data, DeriveInput, Error, Result};
 
 use crate::attr::{
-    field::params::Params, mapping_strategy::MappingStrategy, spanned_item::SpannedItem, to::To,
+    field::{conversion::Conversion, params::Params, with::With},
+    mapping_strategy::MappingStrategy,
+    spanned_item::SpannedItem,
+    to::To,
 };
 
 use self::{
@@ -22,9 +27,9 @@ pub enum Input<'a> {
 }
 
 impl<'a> Input<'a> {
-    pub fn from_syn(node: &'a DeriveInput) -> Result<Self> {
+    pub fn from_syn(node: &'a DeriveInput, validation: &mut Validation) -> Result<Self> {
         match &node.data {
-            Data::Struct(data) => Struct::from_syn(node, data).map(Input::Struct),
+            Data::Struct(data) => Ok(Input::Struct(Struct::from_syn(node, data, validation))),
             _ => Err(Error::new_spanned(
                 node,
                 "Only c style structs are supported",
@@ -33,14 +38,14 @@ impl<'a> Input<'a> {
     }
 }
 
-impl From<Struct<'_>> for HashSet<MappingTree> {
+impl From<Struct<'_>> for OrderedSet<MappingTree> {
     fn from(value: Struct<'_>) -> Self {
-        let mut mapping_trees = HashSet::new();
+        let mut mapping_trees = OrderedSet::new();
         initialize_automatic_mapping_trees(&mut mapping_trees, &value);
         for field in &value.fields {
             add_all_fields_to_automatic_mapping_trees(&mut mapping_trees, field);
             for field_to in &field.attrs.to.to_items {
-                if field_to.params.exclude.1 {
+                if field_to.params.exclude.is_set() {
                     remove_excluded_fields_for_mapping_trees(&mut mapping_trees, field_to, field);
                     continue;
                 }
@@ -64,29 +69,31 @@ impl From<Struct<'_>> for HashSet<MappingTree> {
 }
 
 fn add_with_function(
-    mapping_trees: &mut HashSet<MappingTree>,
+    mapping_trees: &mut OrderedSet<MappingTree>,
     value: &Struct,
     field_to: &To<Params>,
-    with: &SpannedItem<Path, MappingStrategy>,
+    with: &SpannedItem<With, MappingStrategy>,
     field: &field::Field,
 ) {
     if field_to.params.destination.is_none() {
         return;
     }
     let field_dest = field_to.params.destination.as_ref().unwrap();
-    let mut mapping_tree = mapping_trees
-        .get(&MappingTree::new(
-            value.ident.clone(),
-            field_dest.clone(),
-            with.1.clone(),
-            None,
-        ))
-        .unwrap()
-        .clone();
-    mapping_tree.mapping_fields.replace(MappingField {
+    // With functions of strategies without mapping are reported by validation
+    let mut mapping_tree = match mapping_trees.get(&MappingTree::new(
+        value.ident.clone(),
+        field_dest.clone(),
+        with.1.clone(),
+        None,
+    )) {
+        Some(mapping_tree) => mapping_tree.clone(),
+        None => return,
+    };
+    let mut mapping_field = MappingField {
         ty: field.ty.clone(),
         member: field.member.clone(),
-        field: field_to.params.field.clone(),
+        span: field.span(),
+        field: mapping_tree.get_destination_field(field_to.params.field.as_ref(), &field.member),
         strategy: with.1.clone(),
         with: Option::flatten(
             field_to
@@ -96,12 +103,18 @@ fn add_with_function(
                 .find(|&w| w.1 == with.1)
                 .map(|w| w.0.clone()),
         ),
-    });
+        conversion: field_to.params.conversion.clone(),
+        mode: mapping_tree.mode.clone(),
+    };
+    if let Some(current) = mapping_tree.mapping_fields.get(&mapping_field) {
+        mapping_field.conversion = field_to.params.conversion.or(&current.conversion);
+    }
+    mapping_tree.mapping_fields.replace(mapping_field);
     mapping_trees.replace(mapping_tree);
 }
 
 fn add_non_strategy_dependent_fields_params(
-    mapping_trees: &mut HashSet<MappingTree>,
+    mapping_trees: &mut OrderedSet<MappingTree>,
     field_to: &To<Params>,
     field: &field::Field,
 ) {
@@ -121,7 +134,8 @@ fn add_non_strategy_dependent_fields_params(
                 .iter()
                 .filter(|f| f.member == field.member)
                 .map(|f| MappingField {
-                    field: field_to.params.field.clone(),
+                    field: field_to.params.field.clone().or_else(|| f.field.clone()),
+                    conversion: field_to.params.conversion.or(&f.conversion),
                     ..f.clone()
                 })
                 .collect::<Vec<MappingField>>();
@@ -137,7 +151,7 @@ fn add_non_strategy_dependent_fields_params(
 }
 
 fn add_mapping_field_for_additive_mapping_trees(
-    mapping_trees: &mut HashSet<MappingTree>,
+    mapping_trees: &mut OrderedSet<MappingTree>,
     value: &Struct,
     field_to: &To<Params>,
     field_strategy: &MappingStrategy,
@@ -148,16 +162,20 @@ fn add_mapping_field_for_additive_mapping_trees(
     }
     let field_dest = field_to.params.destination.as_ref().unwrap();
     let mut mapping_tree = mapping_trees
-        .get_or_insert(MappingTree::new(
-            value.ident.clone(),
-            field_dest.clone(),
-            field_strategy.clone(),
-            Some(MappingType::Additive),
-        ))
+        .get_or_insert(MappingTree {
+            deny_unmapped: value.denies_unmapped_fields(field_dest),
+            ..MappingTree::new(
+                value.ident.clone(),
+                field_dest.clone(),
+                field_strategy.clone(),
+                Some(MappingType::Additive),
+            )
+        })
         .clone();
-    mapping_tree.mapping_fields.replace(MappingField {
+    let mut mapping_field = MappingField {
         ty: field.ty.clone(),
         member: field.member.clone(),
+        span: field.span(),
         field: field_to.params.field.clone(),
         strategy: field_strategy.clone(),
         with: Option::flatten(
@@ -168,20 +186,25 @@ fn add_mapping_field_for_additive_mapping_trees(
                 .find(|w| &w.1 == field_strategy)
                 .map(|w| w.0.clone()),
         ),
-    });
+        conversion: field_to.params.conversion.clone(),
+        mode: mapping_tree.mode.clone(),
+    };
+    if let Some(current) = mapping_tree.mapping_fields.get(&mapping_field) {
+        mapping_field.conversion = field_to.params.conversion.or(&current.conversion);
+    }
+    mapping_tree.mapping_fields.replace(mapping_field);
     mapping_trees.replace(mapping_tree);
 }
 
 fn remove_excluded_fields_for_mapping_trees(
-    mapping_trees: &mut HashSet<MappingTree>,
+    mapping_trees: &mut OrderedSet<MappingTree>,
     field_to: &To<Params>,
     field: &field::Field,
 ) {
     let mapping_trees_with_excluded_field_removed = mapping_trees
         .iter()
         .filter(|&mapping_tree| {
-            field_to.params.destination.is_none()
-                || &mapping_tree.destination == field_to.params.destination.as_ref().unwrap()
+            field_to.is_excluded_for(&mapping_tree.destination, &mapping_tree.strategy)
         })
         .map(|mapping_tree| {
             let mut mapping_tree = mapping_tree.clone();
@@ -195,7 +218,7 @@ fn remove_excluded_fields_for_mapping_trees(
 }
 
 fn add_all_fields_to_automatic_mapping_trees(
-    mapping_trees: &mut HashSet<MappingTree>,
+    mapping_trees: &mut OrderedSet<MappingTree>,
     field: &field::Field,
 ) {
     let automatic_trees = mapping_trees
@@ -207,8 +230,11 @@ fn add_all_fields_to_automatic_mapping_trees(
                 ty: field.ty.clone(),
                 strategy: mapping_tree.strategy.clone(),
                 member: field.member.clone(),
-                field: None,
+                span: field.span(),
+                field: mapping_tree.get_destination_field(None, &field.member),
                 with: None,
+                conversion: Conversion::default(),
+                mode: mapping_tree.mode.clone(),
             });
             mapping_tree
         })
@@ -218,16 +244,23 @@ fn add_all_fields_to_automatic_mapping_trees(
     }
 }
 
-fn initialize_automatic_mapping_trees(mapping_trees: &mut HashSet<MappingTree>, value: &Struct) {
-    let struct_to = &value.attrs.to;
-    for (strategy, destinations) in &struct_to.destinations_by_strategy {
-        for destination in destinations {
-            mapping_trees.insert(MappingTree::new(
-                value.ident.clone(),
-                destination.clone(),
-                strategy.1.clone(),
-                Some(MappingType::Automatic),
-            ));
+fn initialize_automatic_mapping_trees(mapping_trees: &mut OrderedSet<MappingTree>, value: &Struct) {
+    for struct_to in &value.attrs.to.to_items {
+        for strategy in &struct_to.params.strategies {
+            for destination in &struct_to.params.destinations {
+                mapping_trees.insert(MappingTree {
+                    transparent: struct_to.params.transparent.1,
+                    deny_unmapped: value.denies_unmapped_fields(destination),
+                    mode: struct_to.params.mode.1.clone(),
+                    rename: struct_to.params.rename.clone(),
+                    ..MappingTree::new(
+                        value.ident.clone(),
+                        destination.clone(),
+                        strategy.1.clone(),
+                        Some(MappingType::Automatic),
+                    )
+                });
+            }
         }
     }
 }
@@ -1,13 +1,22 @@
 use std::collections::{HashMap, HashSet};
 
-use syn::{Path, TypePath};
+use syn::{Error, Path, TypePath};
 
-use super::{mapping_strategy::MappingStrategy, spanned_item::SpannedItem};
+use super::{field::target::Target, mapping_strategy::MappingStrategy, spanned_item::SpannedItem};
+
+/// Error of an attribute which couldn't be parsed, it only invalidates the destinations targeted by the attribute,
+/// the whole struct is invalidated if there is no target
+#[derive(Debug, Clone)]
+pub struct UnparsedTo {
+    pub error: Error,
+    pub targets: Vec<Target>,
+}
 
 #[derive(Debug, Clone)]
 pub struct AggregatedTo<T> {
     pub destinations_by_strategy: HashMap<SpannedItem<Path, MappingStrategy>, HashSet<TypePath>>,
     pub to_items: Vec<T>,
+    pub unparsed_items: Vec<UnparsedTo>,
 }
 
 impl<T> AggregatedTo<T> {
@@ -15,12 +24,12 @@ impl<T> AggregatedTo<T> {
         AggregatedTo {
             destinations_by_strategy: HashMap::new(),
             to_items: vec![],
+            unparsed_items: vec![],
         }
     }
     pub fn destinations(&self) -> HashSet<TypePath> {
         self.destinations_by_strategy
             .values()
-            .into_iter()
             .flatten()
             .cloned()
             .collect()
@@ -0,0 +1,41 @@
+use proc_macro2::Ident;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Result, Token, Type, TypePath,
+};
+
+/// Named set of destinations usable as field attribute destination : `group(public = [PersonDto, PersonSummary])`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Group {
+    pub name: Ident,
+    pub destinations: Vec<TypePath>,
+}
+
+impl Parse for Group {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let content;
+        bracketed!(content in input);
+        let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+        if types.is_empty() {
+            return Err(Error::new_spanned(
+                &name,
+                format!("Destination group ({}) should contain destinations", name),
+            ));
+        }
+        let destinations = types
+            .into_iter()
+            .map(|ty| match ty {
+                Type::Path(ty) => Ok(ty),
+                ty => Err(Error::new_spanned(
+                    ty,
+                    "Destination group should only contain type paths",
+                )),
+            })
+            .collect::<Result<Vec<TypePath>>>()?;
+        Ok(Group { name, destinations })
+    }
+}
@@ -0,0 +1,170 @@
+use crate::common::ordered_set::OrderedSet;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parenthesized, parse::ParseStream, punctuated::Punctuated, token, DeriveInput, Error, Expr,
+    LitStr, Path, Result, Token,
+};
+
+use self::group::Group;
+
+use crate::common::{errors::Errors, suggestion::unknown_error};
+
+use super::{
+    data_type::{
+        params::{parse_config, set_flag, set_mode},
+        rename::Rename,
+    },
+    mapping_mode::MappingMode,
+    mapping_strategy::MappingStrategy,
+    spanned_item::SpannedItem,
+};
+
+pub mod group;
+
+const CONFIGS: [&str; 9] = [
+    "strategy",
+    "rename_all",
+    "strip_prefix",
+    "strip_suffix",
+    "add_prefix",
+    "add_suffix",
+    "group",
+    "crate",
+    "to",
+];
+
+const FLAGS: [&str; 4] = ["convert", "deep", "deny_unmapped", "namespaced"];
+
+mod kw {
+    syn::custom_keyword!(group);
+    syn::custom_keyword!(to);
+}
+
+/// Container configuration set with `#[mapper(...)]` attributes,
+/// strategies, mode and rename rules are defaults of struct attributes
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Container {
+    pub groups: Vec<Group>,
+    pub strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+    pub mode: Option<SpannedItem<Path, MappingMode>>,
+    pub rename: Rename,
+    pub krate: Option<Path>,
+    /// Source fields should be mapped or excluded for every destination
+    pub deny_unmapped: Option<SpannedItem<Path, bool>>,
+    /// Bare `#[to(...)]` attributes belong to another derive
+    pub namespaced: Option<SpannedItem<Path, bool>>,
+}
+
+impl Container {
+    /// Path of the mapper crate used in generated code, `::mapper` unless overridden
+    pub fn get_crate_path(&self) -> Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::mapper))
+    }
+    pub fn is_namespaced(&self) -> bool {
+        self.namespaced
+            .as_ref()
+            .map_or(false, |namespaced| namespaced.1)
+    }
+    pub fn get_group(&self, name: &str) -> Option<&Group> {
+        self.groups.iter().find(|group| group.name == name)
+    }
+    fn add_group(&mut self, group: Group) -> Result<()> {
+        if self.get_group(&group.name.to_string()).is_some() {
+            return Err(Error::new_spanned(
+                &group.name,
+                format!(
+                    "Cannot specify multiple time destination group ({})",
+                    group.name
+                ),
+            ));
+        }
+        self.groups.push(group);
+        Ok(())
+    }
+    fn parse_into(&mut self, input: ParseStream) -> Result<()> {
+        let mut errors = Errors::default();
+        while !input.is_empty() {
+            if input.peek(kw::group) {
+                input.parse::<kw::group>()?;
+                let content;
+                parenthesized!(content in input);
+                for group in Punctuated::<Group, Token![,]>::parse_terminated(&content)? {
+                    errors.capture(self.add_group(group));
+                }
+            } else if input.peek(kw::to) && input.peek2(token::Paren) {
+                // Mapping configurations are parsed with struct to attributes
+                input.parse::<kw::to>()?;
+                let content;
+                parenthesized!(content in input);
+                content.parse::<TokenStream>()?;
+            } else if input.peek(Token![crate]) && input.peek2(Token![=]) {
+                let krate = input.parse::<Token![crate]>()?;
+                input.parse::<Token![=]>()?;
+                let path = input.parse::<LitStr>()?;
+                if self.krate.is_some() {
+                    errors.push(Error::new_spanned(
+                        krate,
+                        "Cannot specify multiple time crate config",
+                    ));
+                } else {
+                    self.krate = errors.capture(path.parse::<Path>().map_err(|_| {
+                        Error::new_spanned(&path, "Crate value should be a path string literal")
+                    }));
+                }
+            } else {
+                let arg = input.parse::<Expr>().map_err(|_| {
+                    Error::new(
+                        input.span(),
+                        "Mapper attribute configuration couldn't be parsed",
+                    )
+                })?;
+                errors.capture(match arg {
+                    Expr::Assign(assign) => {
+                        parse_config(assign, &mut self.strategies, &mut self.rename, &CONFIGS)
+                    }
+                    Expr::Path(path) if path.path.is_ident("convert") => {
+                        set_mode(path, &mut self.mode, MappingMode::Convert)
+                    }
+                    Expr::Path(path) if path.path.is_ident("deep") => {
+                        set_mode(path, &mut self.mode, MappingMode::Deep)
+                    }
+                    Expr::Path(path) if path.path.is_ident("deny_unmapped") => {
+                        set_flag(path, &mut self.deny_unmapped)
+                    }
+                    Expr::Path(path) if path.path.is_ident("namespaced") => {
+                        set_flag(path, &mut self.namespaced)
+                    }
+                    Expr::Path(path) => Err(unknown_error(
+                        &path,
+                        "mapper flag",
+                        &path.to_token_stream().to_string(),
+                        &FLAGS,
+                    )),
+                    arg => Err(Error::new_spanned(
+                        arg,
+                        "Mapper attribute configuration should be an assignment, a flag, a group or a to attribute",
+                    )),
+                });
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        errors.finish(())
+    }
+}
+
+/// Parse mapper attributes, valid configurations are kept when others are reported in errors
+pub fn get(node: &DeriveInput, errors: &mut Errors) -> Container {
+    let mut container = Container::default();
+    for attr in &node.attrs {
+        if attr.path.is_ident("mapper") {
+            errors.capture(attr.parse_args_with(|input: ParseStream| container.parse_into(input)));
+        }
+    }
+    container
+}
@@ -0,0 +1,79 @@
+use quote::ToTokens;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token, Error, Member, Path, Result, Token,
+};
+
+use crate::attr::mapping_strategy::MappingStrategy;
+
+mod kw {
+    syn::custom_keyword!(with);
+}
+
+/// Destination of a source field in a struct mapping table
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MappingTarget {
+    /// `source -> field`, `source -> field with function` or `source -> field with(strategy) function`,
+    /// with function strategy defaults to mapper like field attribute `with` config
+    Field {
+        field: Member,
+        with: Option<(Path, MappingStrategy)>,
+    },
+    /// `source -> _`
+    Exclude(Token![_]),
+}
+
+/// Entry of a struct mapping table, equivalent to a field attribute for the attribute destinations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MappingEntry {
+    pub source: Member,
+    pub target: MappingTarget,
+}
+
+impl Parse for MappingEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let source = input.parse::<Member>()?;
+        input.parse::<Token![->]>()?;
+        let target = if input.peek(Token![_]) {
+            MappingTarget::Exclude(input.parse()?)
+        } else {
+            let field = input.parse::<Member>()?;
+            let with = if input.peek(kw::with) {
+                input.parse::<kw::with>()?;
+                let strategy = if input.peek(token::Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let strategy = content.parse::<Path>()?;
+                    MappingStrategy::try_from(strategy.to_token_stream().to_string().as_ref())
+                        .map_err(|e| Error::new_spanned(strategy, e))?
+                } else {
+                    MappingStrategy::default()
+                };
+                Some((input.parse::<Path>()?, strategy))
+            } else {
+                None
+            };
+            MappingTarget::Field { field, with }
+        };
+        Ok(MappingEntry { source, target })
+    }
+}
+
+/// Parse `map(...)` content and append its entries, a source field can only be mapped once
+pub fn parse_mapping_table(input: ParseStream, table: &mut Vec<MappingEntry>) -> Result<()> {
+    let content;
+    parenthesized!(content in input);
+    let entries = Punctuated::<MappingEntry, Token![,]>::parse_terminated(&content)?;
+    for entry in entries {
+        if table.iter().any(|e| e.source == entry.source) {
+            return Err(Error::new_spanned(
+                &entry.source,
+                "Cannot map multiple time the same field in mapping table",
+            ));
+        }
+        table.push(entry);
+    }
+    Ok(())
+}
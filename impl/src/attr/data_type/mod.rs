@@ -1,41 +1,75 @@
 use std::collections::HashSet;
 
+use quote::ToTokens;
+use syn::parse::Parse;
 use syn::DeriveInput;
-use syn::{Error, Result};
+use syn::Error;
 
-use self::params::Params;
+use self::params::{Destinations, Params};
 
-use super::aggregated_to::AggregatedTo;
+use super::aggregated_to::{AggregatedTo, UnparsedTo};
 use super::attrs::Attrs;
+use super::container::Container;
+use super::field::target::Target;
 
 use super::to::To;
+pub mod mapping_table;
 pub mod params;
+pub mod rename;
 
-pub fn get(node: &DeriveInput) -> Result<Attrs<To<Params>>> {
+/// Parse struct attributes, errors of an attribute are kept with its destinations to only invalidate them
+pub fn get<'a>(node: &'a DeriveInput, container: &Container) -> Attrs<To<'a, Params>> {
     let mut aggregated_to = AggregatedTo::new();
     for attr in &node.attrs {
-        if attr.path.is_ident("to") {
-            let to = To::<Params>::new(attr)?;
+        let to_items = match To::from_attribute(
+            attr,
+            |input| Params::parse_with(input, container),
+            true,
+            container.is_namespaced(),
+        ) {
+            Ok(to_items) => to_items,
+            Err(error) => {
+                let targets =
+                    To::from_attribute(attr, Destinations::parse, true, container.is_namespaced())
+                        .map(|to_items| {
+                            to_items
+                                .into_iter()
+                                .flat_map(|to| to.params.0)
+                                .map(Target::Type)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                aggregated_to
+                    .unparsed_items
+                    .push(UnparsedTo { error, targets });
+                continue;
+            }
+        };
+        for to in to_items {
             for strategy in &to.params.strategies {
                 let registered_destinations = aggregated_to
                     .destinations_by_strategy
                     .entry(strategy.clone())
                     .or_insert_with(HashSet::new);
                 let to_destinations = to.params.destinations.clone();
-                let common_destinations = to_destinations.intersection(registered_destinations);
+                let common_destinations = to_destinations
+                    .iter()
+                    .filter(|&destination| registered_destinations.contains(destination));
                 if common_destinations.clone().count() > 0 {
-                    return Err(
-                        Error::new_spanned(attr,
+                    aggregated_to.unparsed_items.push(UnparsedTo {
+                        error: Error::new_spanned(to.original,
                             format!("You cannot specify multiple time same destination for a given strategy, strategy ({}), destinations ({})"
                             ,strategy
-                            ,common_destinations.map(|dest|dest.path.get_ident().unwrap().to_string()).collect::<Vec<String>>().join(",")
-                    )
-                        ));
+                            ,common_destinations.clone().map(|dest|dest.to_token_stream().to_string()).collect::<Vec<String>>().join(",")
+                        )),
+                        targets: common_destinations.cloned().map(Target::Type).collect(),
+                    });
+                    continue;
                 }
                 registered_destinations.extend(to_destinations);
             }
             aggregated_to.to_items.push(to);
         }
     }
-    Ok(Attrs { to: aggregated_to })
+    Attrs { to: aggregated_to }
 }
@@ -1,25 +1,67 @@
-use std::collections::HashSet;
+use crate::common::ordered_set::OrderedSet;
 
-use syn::{parse::Parse, punctuated::Punctuated, Error, Expr, Path, Result, Token, Type, TypePath};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token, Error, Expr, ExprPath, Ident, Path, Result, Token, Type, TypePath,
+};
 
+use super::{
+    mapping_table::{parse_mapping_table, MappingEntry},
+    rename::Rename,
+};
 use crate::{
     attr::{
-        mapping_strategy::{parse_strategy, MappingStrategy, MAX_STRATEGIES_BY_ATTRIBUTE},
+        container::Container,
+        mapping_mode::MappingMode,
+        mapping_strategy::{parse_strategy, MappingStrategy},
         spanned_item::SpannedItem,
     },
-    common::punctuated_extensions::PunctuatedExtensions,
+    common::{
+        errors::Errors,
+        ident::is_primitive,
+        punctuated_extensions::PunctuatedExtensions,
+        suggestion::{suggest, unknown_error},
+    },
 };
 
+mod kw {
+    syn::custom_keyword!(map);
+}
+
+/// Destinations are parsed until a configuration, a mapping table or a flag
+fn is_config(input: ParseStream) -> bool {
+    let fork = input.fork();
+    input.peek2(Token![=])
+        || fork.parse::<Ident>().map_or(false, |ident| {
+            let name = ident.to_string();
+            (CONFIGS.contains(&name.as_ref()) && fork.peek(token::Paren))
+                || (FLAGS.contains(&name.as_ref()) && (fork.is_empty() || fork.peek(Token![,])))
+        })
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Params {
-    pub destinations: HashSet<TypePath>,
-    pub strategies: HashSet<SpannedItem<Path, MappingStrategy>>,
+    pub destinations: OrderedSet<TypePath>,
+    pub strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+    pub transparent: SpannedItem<Path, bool>,
+    pub deny_unmapped: SpannedItem<Path, bool>,
+    pub mode: SpannedItem<Path, MappingMode>,
+    pub rename: Rename,
+    pub mapping_table: Vec<MappingEntry>,
 }
 
 impl Params {
     fn new(
-        destinations: HashSet<TypePath>,
-        mut strategies: HashSet<SpannedItem<Path, MappingStrategy>>,
+        destinations: OrderedSet<TypePath>,
+        mut strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+        transparent: SpannedItem<Path, bool>,
+        deny_unmapped: SpannedItem<Path, bool>,
+        mode: SpannedItem<Path, MappingMode>,
+        rename: Rename,
+        mapping_table: Vec<MappingEntry>,
     ) -> Self {
         if strategies.is_empty() {
             strategies.insert(SpannedItem(None, MappingStrategy::default()));
@@ -27,60 +69,222 @@ impl Params {
         Params {
             destinations,
             strategies,
+            transparent,
+            deny_unmapped,
+            mode,
+            rename,
+            mapping_table,
         }
     }
 }
 
 impl Parse for Params {
-    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let mut destinations = HashSet::new();
-        let mut strategies = HashSet::with_capacity(MAX_STRATEGIES_BY_ATTRIBUTE);
+    fn parse(input: ParseStream) -> Result<Self> {
+        Params::parse_with(input, &Container::default())
+    }
+}
 
-        let args = Punctuated::<Type, Token![,]>::parse_separated_nonempty_until(input, |p| {
-            p.peek2(Token![=])
-        })
-        .map_err(|_| {
-            Error::new(
-                input.span(),
-                "To struct attribute destinations couldn't be parsed",
-            )
-        })?;
+impl Params {
+    /// Parse struct attribute, options not specified in the attribute are taken from container options
+    pub fn parse_with(input: ParseStream, container: &Container) -> Result<Self> {
+        let mut destinations = OrderedSet::new();
+        let mut strategies = OrderedSet::new();
+        let mut transparent = None;
+        let mut deny_unmapped = None;
+        let mut mode = None;
+        let mut rename = Rename::default();
+        let mut mapping_table = Vec::new();
+        let mut errors = Errors::default();
+
+        let args = Punctuated::<Type, Token![,]>::parse_separated_nonempty_until(input, is_config)
+            .map_err(|_| {
+                Error::new(
+                    input.span(),
+                    "To struct attribute destinations couldn't be parsed",
+                )
+            })?;
 
         for arg in args {
             if let Type::Path(ty) = arg {
+                if let Some(ident) = ty.path.get_ident() {
+                    let name = ident.to_string();
+                    if name.starts_with(|c: char| c.is_lowercase())
+                        && !is_primitive(&name)
+                        && suggest(&name, &FLAGS).is_some()
+                    {
+                        errors.push(unknown_error(&ty, "struct flag", &name, &FLAGS));
+                        continue;
+                    }
+                }
                 destinations.insert(ty);
             }
         }
-        if !input.is_empty() {
-            let args =
-                Punctuated::<Expr, Token![,]>::parse_separated_nonempty(input).map_err(|_| {
+        while !input.is_empty() {
+            if input.peek(kw::map) && input.peek2(token::Paren) {
+                input.parse::<kw::map>()?;
+                errors.capture(parse_mapping_table(input, &mut mapping_table));
+            } else {
+                let arg = input.parse::<Expr>().map_err(|_| {
                     Error::new(
                         input.span(),
                         "To struct attribute configuration couldn't be parsed",
                     )
                 })?;
-            for arg in args {
-                if let Expr::Assign(assign) = arg {
-                    parse_config(assign, &mut strategies)?;
-                }
+                errors.capture(match arg {
+                    Expr::Assign(assign) => {
+                        parse_config(assign, &mut strategies, &mut rename, &CONFIGS)
+                    }
+                    Expr::Path(path) => {
+                        parse_flag(path, &mut transparent, &mut deny_unmapped, &mut mode)
+                    }
+                    arg => Err(Error::new_spanned(
+                        arg,
+                        "To struct attribute configuration should be an assignment, a flag or a mapping table",
+                    )),
+                });
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
             }
         }
 
-        Ok(Params::new(destinations, strategies))
+        if strategies.is_empty() {
+            strategies = container.strategies.clone();
+        }
+        errors.finish(Params::new(
+            destinations,
+            strategies,
+            transparent.unwrap_or_default(),
+            deny_unmapped.unwrap_or_default(),
+            mode.or_else(|| container.mode.clone()).unwrap_or_default(),
+            rename.or(&container.rename),
+            mapping_table,
+        ))
     }
 }
 
-fn parse_config(
+/// Destinations of a struct attribute, parsed to find destinations of an attribute whose configuration is invalid
+pub struct Destinations(pub Vec<TypePath>);
+
+impl Parse for Destinations {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let args = Punctuated::<Type, Token![,]>::parse_separated_nonempty_until(input, is_config)?;
+        input.parse::<TokenStream>()?;
+        Ok(Destinations(
+            args.into_iter()
+                .filter_map(|arg| match arg {
+                    Type::Path(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    }
+}
+
+pub const CONFIGS: [&str; 7] = [
+    "strategy",
+    "rename_all",
+    "strip_prefix",
+    "strip_suffix",
+    "add_prefix",
+    "add_suffix",
+    "map",
+];
+
+const FLAGS: [&str; 5] = ["transparent", "deny_unmapped", "convert", "deep", "direct"];
+
+/// Parse strategy and rename configurations, configs are listed in unknown configuration error
+pub fn parse_config(
     assign: syn::ExprAssign,
-    strategies: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
+    strategies: &mut OrderedSet<SpannedItem<Path, MappingStrategy>>,
+    rename: &mut Rename,
+    configs: &[&str],
 ) -> Result<()> {
-    if let Expr::Path(config) = *assign.left {
-        if config.path.is_ident("strategy") {
-            if let Expr::Path(strategy_expr) = *assign.right {
-                let founded_strategies = parse_strategy(&strategy_expr.path, strategies)?;
-                strategies.extend(founded_strategies);
+    match *assign.left {
+        Expr::Path(config) => {
+            if config.path.is_ident("strategy") {
+                if let Expr::Path(strategy_expr) = *assign.right {
+                    let founded_strategies = parse_strategy(&strategy_expr.path, strategies)?;
+                    strategies.extend(founded_strategies);
+                } else {
+                    return Err(Error::new_spanned(
+                        assign.right,
+                        "Strategy value should be a strategy identifier : [into, mapper, all]",
+                    ));
+                }
+            } else if !rename.parse_config(&config, &assign.right)? {
+                return Err(unknown_error(
+                    &config,
+                    "configuration",
+                    &config.to_token_stream().to_string(),
+                    configs,
+                ));
             }
+            Ok(())
         }
+        left => Err(Error::new_spanned(
+            left,
+            "Configuration key should be an identifier",
+        )),
     }
+}
+
+fn parse_flag(
+    expr_path: ExprPath,
+    transparent: &mut Option<SpannedItem<Path, bool>>,
+    deny_unmapped: &mut Option<SpannedItem<Path, bool>>,
+    mode: &mut Option<SpannedItem<Path, MappingMode>>,
+) -> Result<()> {
+    if expr_path.path.is_ident("transparent") {
+        set_flag(expr_path, transparent)?;
+    } else if expr_path.path.is_ident("deny_unmapped") {
+        set_flag(expr_path, deny_unmapped)?;
+    } else if expr_path.path.is_ident("convert") {
+        set_mode(expr_path, mode, MappingMode::Convert)?;
+    } else if expr_path.path.is_ident("deep") {
+        set_mode(expr_path, mode, MappingMode::Deep)?;
+    } else if expr_path.path.is_ident("direct") {
+        set_mode(expr_path, mode, MappingMode::Direct)?;
+    } else {
+        return Err(unknown_error(
+            &expr_path,
+            "struct flag",
+            &expr_path.to_token_stream().to_string(),
+            &FLAGS,
+        ));
+    }
+    Ok(())
+}
+
+pub fn set_flag(expr_path: ExprPath, flag: &mut Option<SpannedItem<Path, bool>>) -> Result<()> {
+    if flag.is_some() {
+        return Err(Error::new_spanned(
+            &expr_path,
+            format!(
+                "Cannot specify multiple time {} flag",
+                expr_path.to_token_stream()
+            ),
+        ));
+    }
+    *flag = Some(SpannedItem::new(expr_path.path, true));
     Ok(())
 }
+
+pub fn set_mode(
+    expr_path: ExprPath,
+    mode: &mut Option<SpannedItem<Path, MappingMode>>,
+    new_mode: MappingMode,
+) -> Result<()> {
+    if let Some(mode) = mode {
+        Err(Error::new_spanned(
+            expr_path,
+            format!(
+                "Cannot specify multiple mapping modes, ({}) is already specified",
+                mode
+            ),
+        ))
+    } else {
+        *mode = Some(SpannedItem::new(expr_path.path, new_mode));
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use syn::{Error, Expr, ExprLit, ExprPath, Lit, Result};
+use thiserror::Error;
+
+use crate::attr::rename_rule::RenameRule;
+
+#[derive(Error, Debug)]
+pub enum RenameError {
+    #[error("prefix ({0}) couldn't be stripped")]
+    PrefixNotFound(String),
+    #[error("suffix ({0}) couldn't be stripped")]
+    SuffixNotFound(String),
+    #[error("renamed field ({0}) isn't a valid identifier")]
+    InvalidIdent(String),
+}
+
+/// Rules computing automatic destination field names, applied in order : strip, case conversion, add
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Rename {
+    pub rule: Option<RenameRule>,
+    pub strip_prefix: Option<String>,
+    pub strip_suffix: Option<String>,
+    pub add_prefix: Option<String>,
+    pub add_suffix: Option<String>,
+}
+
+impl Rename {
+    pub fn is_empty(&self) -> bool {
+        self == &Rename::default()
+    }
+    /// Rules not specified are taken from other rules
+    pub fn or(self, other: &Rename) -> Rename {
+        Rename {
+            rule: self.rule.or_else(|| other.rule.clone()),
+            strip_prefix: self.strip_prefix.or_else(|| other.strip_prefix.clone()),
+            strip_suffix: self.strip_suffix.or_else(|| other.strip_suffix.clone()),
+            add_prefix: self.add_prefix.or_else(|| other.add_prefix.clone()),
+            add_suffix: self.add_suffix.or_else(|| other.add_suffix.clone()),
+        }
+    }
+    pub fn apply(&self, name: &str) -> std::result::Result<String, RenameError> {
+        let mut name = name;
+        if let Some(prefix) = &self.strip_prefix {
+            name = name
+                .strip_prefix(prefix.as_str())
+                .ok_or_else(|| RenameError::PrefixNotFound(prefix.clone()))?;
+        }
+        if let Some(suffix) = &self.strip_suffix {
+            name = name
+                .strip_suffix(suffix.as_str())
+                .ok_or_else(|| RenameError::SuffixNotFound(suffix.clone()))?;
+        }
+        let name = match &self.rule {
+            Some(rule) => rule.apply(name),
+            None => name.to_string(),
+        };
+        let name = format!(
+            "{}{}{}",
+            self.add_prefix.as_deref().unwrap_or_default(),
+            name,
+            self.add_suffix.as_deref().unwrap_or_default()
+        );
+        if is_ident(&name) {
+            Ok(name)
+        } else {
+            Err(RenameError::InvalidIdent(name))
+        }
+    }
+    /// Parse rename configuration, returns false if config isn't a rename configuration
+    pub fn parse_config(&mut self, config: &ExprPath, value: &Expr) -> Result<bool> {
+        let (name, current) = if config.path.is_ident("rename_all") {
+            if self.rule.is_some() {
+                return Err(multiple_config_error(config, "rename_all"));
+            }
+            let rule = parse_str(value, "Rename all")?;
+            self.rule = Some(
+                RenameRule::try_from(rule.as_ref()).map_err(|e| Error::new_spanned(value, e))?,
+            );
+            return Ok(true);
+        } else if config.path.is_ident("strip_prefix") {
+            ("strip_prefix", &mut self.strip_prefix)
+        } else if config.path.is_ident("strip_suffix") {
+            ("strip_suffix", &mut self.strip_suffix)
+        } else if config.path.is_ident("add_prefix") {
+            ("add_prefix", &mut self.add_prefix)
+        } else if config.path.is_ident("add_suffix") {
+            ("add_suffix", &mut self.add_suffix)
+        } else {
+            return Ok(false);
+        };
+        if current.is_some() {
+            return Err(multiple_config_error(config, name));
+        }
+        *current = Some(parse_str(value, "Prefix and suffix")?);
+        Ok(true)
+    }
+}
+
+fn multiple_config_error(config: &ExprPath, name: &str) -> Error {
+    Error::new_spanned(
+        config,
+        format!("Cannot specify multiple time {} config", name),
+    )
+}
+
+fn parse_str(value: &Expr, name: &str) -> Result<String> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(value),
+        ..
+    }) = value
+    {
+        Ok(value.value())
+    } else {
+        Err(Error::new_spanned(
+            value,
+            format!("{} value should be a string literal", name),
+        ))
+    }
+}
+
+fn is_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            !["_", "self", "Self", "super", "crate"].contains(&name)
+                && chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use quote::ToTokens;
+use syn::{Error, Member, Path, Result};
+
+use crate::attr::pointer::Pointer;
+
+/// Collection adaptation applied to the source field, `with` function is then applied to each element
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Collection {
+    ByKey(Member),
+    Values,
+    Each,
+}
+
+impl Display for Collection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Collection::ByKey(_) => write!(f, "collect_by_key"),
+            Collection::Values => write!(f, "values"),
+            Collection::Each => write!(f, "each"),
+        }
+    }
+}
+
+/// Transformations applied to the source field value around the `with` function
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Conversion {
+    pub pointer: Option<Pointer>,
+    pub unwrap_newtype: bool,
+    pub wrap_newtype: Option<Path>,
+    pub collection: Option<Collection>,
+    /// Field is cloned or moved whatever the mode of its mapping
+    pub direct: bool,
+}
+
+impl Conversion {
+    pub fn is_empty(&self) -> bool {
+        self == &Conversion::default()
+    }
+    /// Conversion of an attribute, or the conversion set by a previous attribute of the same destination if empty
+    pub fn or(&self, previous: &Conversion) -> Conversion {
+        if self.is_empty() {
+            previous.clone()
+        } else {
+            self.clone()
+        }
+    }
+    pub fn set_collection<T: ToTokens>(&mut self, tokens: T, collection: Collection) -> Result<()> {
+        match &self.collection {
+            Some(current) if current != &collection => Err(Error::new_spanned(
+                tokens,
+                format!(
+                    "Cannot specify multiple collection conversions, ({}) is already specified",
+                    current
+                ),
+            )),
+            _ => {
+                self.collection = Some(collection);
+                Ok(())
+            }
+        }
+    }
+}
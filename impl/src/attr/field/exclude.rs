@@ -0,0 +1,87 @@
+use syn::{Error, Expr, ExprCall, Path, Result};
+
+use crate::{
+    attr::{
+        mapping_strategy::{parse_strategy, MappingStrategy},
+        spanned_item::SpannedItem,
+    },
+    common::ordered_set::OrderedSet,
+};
+
+/// Field exclusion, `exclude` excludes every strategy and `exclude(strategy = ...)` only listed strategies
+#[derive(Clone, Debug, Default)]
+pub struct Exclude {
+    pub path: Option<Path>,
+    pub strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+}
+
+impl Exclude {
+    pub fn new(path: Path) -> Self {
+        Exclude {
+            path: Some(path),
+            strategies: OrderedSet::new(),
+        }
+    }
+    pub fn is_set(&self) -> bool {
+        self.path.is_some()
+    }
+    pub fn has_strategy(&self, strategy: &MappingStrategy) -> bool {
+        self.is_set()
+            && (self.strategies.is_empty()
+                || self
+                    .strategies
+                    .contains(&SpannedItem::new_empty(strategy.clone())))
+    }
+    /// Set exclusion from `exclude` flag
+    pub fn set(&mut self, path: Path) -> Result<()> {
+        if self.is_set() {
+            return Err(Error::new_spanned(
+                path,
+                "Cannot specify multiple time exclude flag",
+            ));
+        }
+        self.path = Some(path);
+        Ok(())
+    }
+    /// Set exclusion from `exclude(strategy = ...)` call
+    pub fn set_from_call(&mut self, call: &ExprCall) -> Result<()> {
+        let path = match &*call.func {
+            Expr::Path(func) => func.path.clone(),
+            func => return Err(Error::new_spanned(func, "Exclude should be an identifier")),
+        };
+        if call.args.is_empty() {
+            return Err(Error::new_spanned(
+                call,
+                "Exclude configuration should contains strategies",
+            ));
+        }
+        let mut strategies = OrderedSet::new();
+        for arg in &call.args {
+            match arg {
+                Expr::Assign(assign) => match (&*assign.left, &*assign.right) {
+                    (Expr::Path(config), Expr::Path(strategy))
+                        if config.path.is_ident("strategy") =>
+                    {
+                        let found_strategies = parse_strategy(&strategy.path, &strategies)?;
+                        strategies.extend(found_strategies);
+                    }
+                    _ => {
+                        return Err(Error::new_spanned(
+                            assign,
+                            "Exclude configuration only accepts strategies : strategy = [into, mapper, all]",
+                        ))
+                    }
+                },
+                arg => {
+                    return Err(Error::new_spanned(
+                        arg,
+                        "Exclude configuration only accepts strategies : strategy = [into, mapper, all]",
+                    ))
+                }
+            }
+        }
+        self.set(path)?;
+        self.strategies = strategies;
+        Ok(())
+    }
+}
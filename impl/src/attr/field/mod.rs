@@ -1,24 +1,69 @@
 use std::collections::HashSet;
 
-use syn::{Error, Path, TypePath};
+use quote::ToTokens;
+use syn::{Error, Result, TypePath};
 
-use self::params::Params;
+use syn::parse::Parse;
+
+use self::{
+    params::{Params, Targets},
+    target::Target,
+    with::With,
+};
 
 use super::mapping_strategy::MappingStrategy;
 
+pub mod conversion;
+pub mod exclude;
 pub mod params;
+pub mod target;
+pub mod with;
+
+use crate::common::{
+    errors::Errors,
+    ident::is_primitive,
+    suggestion::{suggest, unknown_error},
+};
 
-use super::aggregated_to::AggregatedTo;
+use super::aggregated_to::{AggregatedTo, UnparsedTo};
 use super::attrs::Attrs;
+use super::container::Container;
 use super::to::To;
 
-impl To<'_, Params> {
-    pub fn get_with_by_strategy(&self, strategy: &MappingStrategy) -> Option<Path> {
+impl<'a> To<'a, Params> {
+    /// Split an attribute targeting multiple destination types or groups into one attribute by destination
+    pub fn split_by_destination(self, container: &Container) -> Result<Vec<To<'a, Params>>> {
+        let mut destinations: Vec<TypePath> = Vec::new();
+        let mut errors = Errors::default();
+        for target in &self.params.targets {
+            if let Target::Type(ty) = target {
+                destinations.extend(
+                    errors
+                        .capture(resolve_group(ty, container))
+                        .unwrap_or_default(),
+                );
+            }
+        }
+        errors.finish(())?;
+        if destinations.is_empty() {
+            return Ok(vec![self]);
+        }
+        Ok(destinations
+            .into_iter()
+            .map(|ty| {
+                let mut to = self.clone();
+                to.params.targets = vec![Target::Type(ty.clone())];
+                to.params.destination = Some(ty);
+                to
+            })
+            .collect())
+    }
+    pub fn get_with_by_strategy(&self, strategy: &MappingStrategy) -> Option<With> {
         let with = self.params.with.iter().find(|&w| &w.1 == strategy);
         Option::flatten(with.map(|w| w.0.clone()))
     }
-    pub fn is_excluded_for_destination(&self, destination: &TypePath) -> bool {
-        self.params.exclude.1
+    pub fn is_excluded_for(&self, destination: &TypePath, strategy: &MappingStrategy) -> bool {
+        self.params.exclude.has_strategy(strategy)
             && if let Some(dest) = &self.params.destination {
                 dest == destination
             } else {
@@ -50,31 +95,93 @@ impl To<'_, Params> {
     }
 }
 
-pub fn get(input: &syn::Field) -> syn::Result<Attrs<To<Params>>> {
+/// Destinations of a group declared in mapper attribute or the type itself,
+/// lowercase identifiers close to a group name or a flag are rejected as typos
+pub fn resolve_group(ty: &TypePath, container: &Container) -> Result<Vec<TypePath>> {
+    let ident = match ty.path.get_ident() {
+        Some(ident) if ty.qself.is_none() => ident,
+        _ => return Ok(vec![ty.clone()]),
+    };
+    if let Some(group) = container.get_group(&ident.to_string()) {
+        return Ok(group.destinations.clone());
+    }
+    let name = ident.to_string();
+    if name.starts_with(|c: char| c.is_lowercase()) && !is_primitive(&name) {
+        let options = container
+            .groups
+            .iter()
+            .map(|group| group.name.to_string())
+            .chain(params::FLAGS.iter().map(|flag| flag.to_string()))
+            .collect::<Vec<String>>();
+        let options = options.iter().map(String::as_str).collect::<Vec<&str>>();
+        if suggest(&name, &options).is_some() {
+            return Err(unknown_error(
+                ty,
+                "destination group or flag",
+                &name,
+                &options,
+            ));
+        }
+    }
+    Ok(vec![ty.clone()])
+}
+
+/// Parse field attributes, errors of an attribute are kept with its targets to only invalidate its destinations
+pub fn get<'a>(input: &'a syn::Field, container: &Container) -> Attrs<To<'a, Params>> {
     let mut aggregated_to = AggregatedTo::new();
     for attr in &input.attrs {
-        if attr.path.is_ident("to") {
-            let to = To::<Params>::new(attr)?;
-            for strategy in &to.params.strategies {
-                let registered_destinations = aggregated_to
-                    .destinations_by_strategy
-                    .entry(strategy.clone())
-                    .or_insert_with(HashSet::new);
-                if let Some(to_destination) = &to.params.destination {
-                    if let Some(destination) =
-                        registered_destinations.replace(to_destination.clone())
-                    {
-                        return Err(
-                            Error::new_spanned(attr,
-                                format!("You cannot specify multiple time same destination for a given strategy, strategy ({}), destination ({})"
-                                ,strategy
-                                ,destination.path.get_ident().unwrap()
-                        )));
+        let to_attributes =
+            match To::from_attribute(attr, Params::parse, false, container.is_namespaced()) {
+                Ok(to_attributes) => to_attributes,
+                Err(error) => {
+                    let targets =
+                        To::from_attribute(attr, Targets::parse, false, container.is_namespaced())
+                            .map(|to_items| {
+                                to_items.into_iter().flat_map(|to| to.params.0).collect()
+                            })
+                            .unwrap_or_default();
+                    aggregated_to
+                        .unparsed_items
+                        .push(UnparsedTo { error, targets });
+                    continue;
+                }
+            };
+        for to_attribute in to_attributes {
+            let targets = to_attribute.params.targets.clone();
+            let split_to = match to_attribute.split_by_destination(container) {
+                Ok(split_to) => split_to,
+                Err(error) => {
+                    aggregated_to
+                        .unparsed_items
+                        .push(UnparsedTo { error, targets });
+                    continue;
+                }
+            };
+            'to: for to in split_to {
+                for strategy in &to.params.strategies {
+                    let registered_destinations = aggregated_to
+                        .destinations_by_strategy
+                        .entry(strategy.clone())
+                        .or_insert_with(HashSet::new);
+                    if let Some(to_destination) = &to.params.destination {
+                        if let Some(destination) =
+                            registered_destinations.replace(to_destination.clone())
+                        {
+                            aggregated_to.unparsed_items.push(UnparsedTo {
+                                error: Error::new_spanned(to.original,
+                                    format!("You cannot specify multiple time same destination for a given strategy, strategy ({}), destination ({})"
+                                    ,strategy
+                                    ,destination.to_token_stream()
+                                )),
+                                targets: vec![Target::Type(destination)],
+                            });
+                            continue 'to;
+                        }
                     }
                 }
+                aggregated_to.to_items.push(to);
             }
-            aggregated_to.to_items.push(to);
         }
     }
-    Ok(Attrs { to: aggregated_to })
+    Attrs { to: aggregated_to }
 }
@@ -1,47 +1,84 @@
-use std::collections::HashSet;
+use crate::common::ordered_set::OrderedSet;
 
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use syn::{
-    custom_keyword, parse::Parse, punctuated::Punctuated, token::Comma, Error, Expr, ExprPath,
-    Path, Token, Type, TypePath,
+    custom_keyword,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{self, Comma},
+    Error, Expr, ExprLit, ExprPath, Ident, Index, Lit, Member, Path, Token, TypePath,
 };
 use thiserror::Error;
 
-use crate::attr::{
-    mapping_strategy::{parse_strategy, MappingStrategy},
-    spanned_item::SpannedItem,
+use crate::{
+    attr::{
+        mapping_strategy::{parse_strategy, MappingStrategy},
+        pointer::Pointer,
+        spanned_item::SpannedItem,
+    },
+    common::{
+        errors::Errors,
+        ident::{is_primitive, parse_ident},
+        punctuated_extensions::PunctuatedExtensions,
+        suggestion::{suggest, unknown_error},
+    },
+};
+
+use super::{
+    conversion::{Collection, Conversion},
+    exclude::Exclude,
+    target::Target,
+    with::With,
 };
 
 #[derive(Error, Debug)]
 pub enum ParamsError {
     #[error("Excluded field attribute couldn't have other configurations fields")]
     ExcludedField,
+    #[error("Excluded field attribute couldn't have strategy configuration, use exclude(strategy = ...) to exclude a field for a strategy")]
+    ExcludedFieldStrategy,
+    #[error("Wildcard destination couldn't have strategy configuration")]
+    WildcardStrategy,
 }
 #[derive(Clone, Debug)]
 pub struct Params {
+    pub targets: Vec<Target>,
     pub destination: Option<TypePath>,
-    pub field: Option<Path>,
-    pub with: HashSet<SpannedItem<Path, MappingStrategy>>,
-    pub exclude: SpannedItem<Path, bool>,
-    pub strategies: HashSet<SpannedItem<Path, MappingStrategy>>,
+    pub field: Option<Member>,
+    pub with: OrderedSet<SpannedItem<With, MappingStrategy>>,
+    pub exclude: Exclude,
+    pub strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+    pub conversion: Conversion,
 }
 
 impl Params {
     pub fn new(
+        targets: Vec<Target>,
         destination: Option<TypePath>,
-        field: Option<Path>,
-        with: HashSet<SpannedItem<Path, MappingStrategy>>,
-        exclude: SpannedItem<Path, bool>,
-        strategies: HashSet<SpannedItem<Path, MappingStrategy>>,
+        field: Option<Member>,
+        with: OrderedSet<SpannedItem<With, MappingStrategy>>,
+        exclude: Exclude,
+        strategies: OrderedSet<SpannedItem<Path, MappingStrategy>>,
+        conversion: Conversion,
     ) -> Result<Self, ParamsError> {
-        if exclude.1 && (field.is_some() || !with.is_empty()) {
+        if exclude.is_set() && (field.is_some() || !with.is_empty() || !conversion.is_empty()) {
             Err(ParamsError::ExcludedField)
+        } else if exclude.is_set() && !strategies.is_empty() {
+            Err(ParamsError::ExcludedFieldStrategy)
+        } else if !strategies.is_empty() && targets.iter().any(|t| matches!(t, Target::Wildcard(_)))
+        {
+            Err(ParamsError::WildcardStrategy)
         } else {
             Ok(Self {
+                targets,
                 destination,
                 field,
                 with,
                 exclude,
                 strategies,
+                conversion,
             })
         }
     }
@@ -51,62 +88,102 @@ impl Parse for Params {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         custom_keyword!(exclude);
         if input.peek(exclude) {
-            let exclude_path = input.parse::<Path>().ok();
+            let mut exclude = Exclude::default();
+            match input.parse::<Expr>()? {
+                Expr::Call(call) => exclude.set_from_call(&call)?,
+                Expr::Path(path) => exclude.set(path.path)?,
+                arg => {
+                    return Err(Error::new_spanned(
+                        arg,
+                        "Exclude should be a flag or a call",
+                    ))
+                }
+            }
             if input.is_empty() {
                 return Params::new(
+                    vec![],
                     None,
                     None,
-                    HashSet::with_capacity(0),
-                    SpannedItem(exclude_path, true),
-                    HashSet::with_capacity(0),
+                    OrderedSet::new(),
+                    exclude,
+                    OrderedSet::new(),
+                    Conversion::default(),
                 )
                 .map_err(|err| syn::Error::new(input.span(), err));
             } else {
                 return Err(Error::new(input.span(), "Cannot exclude field for all destinations if exclude arg isn't the only argument of the attribute"));
             }
         }
-        let mut field: Option<Path> = None;
-        let mut with = HashSet::new();
-        let mut exclude_config = None;
-        let mut strategies = HashSet::new();
+        let mut field: Option<Member> = None;
+        let mut with = OrderedSet::new();
+        let mut exclude = Exclude::default();
+        let mut strategies = OrderedSet::new();
+        let mut conversion = Conversion::default();
 
-        if let Ok(Type::Path(ty)) = &input.parse::<Type>() {
+        if let Ok(targets) =
+            Punctuated::<Target, Token![,]>::parse_separated_nonempty_until(input, is_config)
+        {
             if input.is_empty() {
+                Err(unknown_trailing_flag(&targets).unwrap_or_else(|| {
+                    Error::new_spanned(&targets, "To field attribute should contains configuration")
+                }))
+            } else if !targets.trailing_punct() {
                 Err(Error::new_spanned(
-                    ty,
-                    "To field attribute should contains configuration",
+                    &targets,
+                    "To field attribute destination should be followed by comma",
                 ))
             } else {
-                input.parse::<Token![,]>().map_err(|_| {
-                    Error::new_spanned(
-                        ty,
-                        "To field attribute destination should be followed by comma",
-                    )
-                })
+                Ok(())
             }?;
+            let targets = targets.into_iter().collect::<Vec<Target>>();
+            if targets.len() > 1 && targets.iter().any(|t| matches!(t, Target::Wildcard(_))) {
+                return Err(Error::new(
+                    input.span(),
+                    "Wildcard destination couldn't be combined with other destinations",
+                ));
+            }
             let args =
                 Punctuated::<Expr, Token![,]>::parse_separated_nonempty(input).map_err(|_| {
-                    Error::new_spanned(ty, "To field attribute configuration couldn't be parsed")
+                    Error::new(
+                        input.span(),
+                        "To field attribute configuration couldn't be parsed",
+                    )
                 })?;
+            let mut errors = Errors::default();
+            let mut strategy_config = None;
             for arg in args {
-                match arg {
-                    Expr::Assign(assign) => {
-                        parse_config(assign, &mut field, &mut with, &mut strategies)?;
-                    }
-                    Expr::Path(path) => {
-                        parse_flag(path, &mut exclude_config)?;
+                if let Expr::Assign(assign) = &arg {
+                    if let Expr::Path(config) = &*assign.left {
+                        if config.path.is_ident("strategy") && strategy_config.is_none() {
+                            strategy_config = Some(assign.clone());
+                        }
                     }
-                    _ => (),
                 }
+                errors.capture(match arg {
+                    Expr::Assign(assign) => parse_config(
+                        assign,
+                        &mut field,
+                        &mut with,
+                        &mut strategies,
+                        &mut conversion,
+                    ),
+                    Expr::Path(path) => parse_flag(path, &mut exclude, &mut conversion),
+                    Expr::Call(call) => parse_call(call, &mut with, &mut exclude, &mut conversion),
+                    arg => Err(Error::new_spanned(
+                        arg,
+                        "To field attribute configuration should be an assignment, a flag or a call",
+                    )),
+                });
             }
-            Params::new(
-                Some(ty.clone()),
-                field,
-                with,
-                exclude_config.unwrap_or_default(),
-                strategies,
+            errors.finish(())?;
+            Params::new(targets, None, field, with, exclude, strategies, conversion).map_err(
+                |err| match (&err, strategy_config) {
+                    (ParamsError::ExcludedFieldStrategy, Some(strategy_config)) => {
+                        syn::Error::new_spanned(strategy_config, err)
+                    }
+                    _ => syn::Error::new(input.span(), err),
+                },
             )
-            .map_err(|err| syn::Error::new(input.span(), err))
         } else {
             Err(Error::new(
                 input.span(),
@@ -116,72 +193,282 @@ impl Parse for Params {
     }
 }
 
+/// Targets of a field attribute, parsed to find destinations of an attribute whose configuration is invalid
+pub struct Targets(pub Vec<Target>);
+
+impl Parse for Targets {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let targets =
+            Punctuated::<Target, Token![,]>::parse_separated_nonempty_until(input, is_config)?;
+        input.parse::<TokenStream>()?;
+        Ok(Targets(targets.into_iter().collect()))
+    }
+}
+
+const CONFIGS: [&str; 5] = [
+    "field",
+    "with",
+    "strategy",
+    "collect_by_key",
+    "wrap_newtype",
+];
+
+pub const FLAGS: [&str; 9] = [
+    "exclude",
+    "direct",
+    "unwrap_newtype",
+    "values",
+    "each",
+    "boxed",
+    "rc",
+    "arc",
+    "deref",
+];
+
+const CALLS: [&str; 2] = ["each", "exclude"];
+
+/// Last destination of an attribute without configuration close to a flag name, it's a misspelled flag
+fn unknown_trailing_flag(targets: &Punctuated<Target, Token![,]>) -> Option<Error> {
+    let ty = match targets.last() {
+        Some(Target::Type(ty)) if ty.qself.is_none() => ty,
+        _ => return None,
+    };
+    let name = ty.path.get_ident()?.to_string();
+    if name.starts_with(|c: char| c.is_lowercase())
+        && !is_primitive(&name)
+        && suggest(&name, &FLAGS).is_some()
+    {
+        Some(unknown_error(ty, "field flag", &name, &FLAGS))
+    } else {
+        None
+    }
+}
+
+/// Destinations are parsed until a configuration, a call or a flag
+fn is_config(input: ParseStream) -> bool {
+    let fork = input.fork();
+    input.peek2(Token![=])
+        || input.peek2(token::Paren)
+        || fork.parse::<Ident>().map_or(false, |ident| {
+            FLAGS.contains(&ident.to_string().as_ref()) && (fork.is_empty() || fork.peek(Token![,]))
+        })
+}
+
 fn parse_config(
     assign: syn::ExprAssign,
-    field: &mut Option<Path>,
-    with: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
-    strategies: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
+    field: &mut Option<Member>,
+    with: &mut OrderedSet<SpannedItem<With, MappingStrategy>>,
+    strategies: &mut OrderedSet<SpannedItem<Path, MappingStrategy>>,
+    conversion: &mut Conversion,
 ) -> syn::Result<()> {
+    let span = assign.span();
     match *assign.left {
         Expr::Path(config) => {
             if config.path.is_ident("field") {
-                if let Expr::Path(dst_field) = *assign.right {
-                    *field = Some(dst_field.path);
+                if field.is_some() {
+                    return Err(Error::new_spanned(
+                        config,
+                        "Cannot specify multiple time field config",
+                    ));
                 }
+                *field = Some(parse_field(*assign.right)?);
             } else if config.path.is_ident("with") {
-                parse_with_value(&assign.right, with, None)?;
+                parse_with_value(&assign.right, span, with, None)?;
             } else if config.path.is_ident("strategy") {
                 if let Expr::Path(strategy_expr) = *assign.right {
                     let found_strategies = parse_strategy(&strategy_expr.path, strategies)?;
                     strategies.extend(found_strategies);
+                } else {
+                    return Err(Error::new_spanned(
+                        assign.right,
+                        "Strategy value should be a strategy identifier : [into, mapper, all]",
+                    ));
+                }
+            } else if config.path.is_ident("collect_by_key") {
+                let key = match *assign.right {
+                    Expr::Path(key) if key.path.get_ident().is_some() => {
+                        Member::Named(key.path.get_ident().unwrap().clone())
+                    }
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Int(key), ..
+                    }) => Member::Unnamed(Index {
+                        index: key.base10_parse()?,
+                        span: key.span(),
+                    }),
+                    key => {
+                        return Err(Error::new_spanned(
+                            key,
+                            "Collect by key value should be an element field name or index",
+                        ))
+                    }
+                };
+                conversion.set_collection(&config, Collection::ByKey(key))?;
+            } else if config.path.is_ident("wrap_newtype") {
+                if conversion.wrap_newtype.is_some() {
+                    return Err(Error::new_spanned(
+                        config,
+                        "Cannot specify multiple time wrap_newtype config",
+                    ));
+                }
+                if let Expr::Path(newtype) = *assign.right {
+                    conversion.wrap_newtype = Some(newtype.path);
+                } else {
+                    return Err(Error::new_spanned(
+                        assign.right,
+                        "Wrap newtype value should be a type path",
+                    ));
                 }
+            } else {
+                return Err(unknown_error(
+                    &config,
+                    "field configuration",
+                    &config.to_token_stream().to_string(),
+                    &CONFIGS,
+                ));
             }
         }
         Expr::Call(config) => {
-            if let Expr::Path(func) = *config.func {
-                parse_with_strategy(func, &config.args, &assign.right, with)?;
+            parse_with_strategy(&config.func, &config.args, &assign.right, span, with)?;
+        }
+        left => {
+            return Err(Error::new_spanned(
+                left,
+                "To field attribute configuration key should be an identifier",
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn parse_field(value: Expr) -> syn::Result<Member> {
+    let member = match &value {
+        Expr::Path(dst_field) => dst_field.path.get_ident().cloned().map(Member::Named),
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(dst_field),
+            ..
+        }) => parse_ident(&dst_field.value(), dst_field.span()).map(Member::Named),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(dst_field),
+            ..
+        }) => dst_field.base10_parse().ok().map(|index| {
+            Member::Unnamed(Index {
+                index,
+                span: dst_field.span(),
+            })
+        }),
+        _ => None,
+    };
+    member.ok_or_else(|| {
+        Error::new_spanned(
+            value,
+            "Field value should be an identifier, a string literal containing an identifier or a tuple index",
+        )
+    })
+}
+
+fn parse_call(
+    call: syn::ExprCall,
+    with: &mut OrderedSet<SpannedItem<With, MappingStrategy>>,
+    exclude: &mut Exclude,
+    conversion: &mut Conversion,
+) -> syn::Result<()> {
+    match &*call.func {
+        Expr::Path(func) if func.path.is_ident("exclude") => {
+            exclude.set_from_call(&call)?;
+        }
+        Expr::Path(func) if func.path.is_ident("each") => {
+            for arg in &call.args {
+                match arg {
+                    Expr::Assign(assign) => match &*assign.left {
+                        Expr::Path(config) if config.path.is_ident("with") => {
+                            parse_with_value(&assign.right, assign.span(), with, None)?;
+                        }
+                        Expr::Call(config) => {
+                            parse_with_strategy(
+                                &config.func,
+                                &config.args,
+                                &assign.right,
+                                assign.span(),
+                                with,
+                            )?;
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                assign,
+                                "Each configuration only accepts with functions",
+                            ))
+                        }
+                    },
+                    _ => {
+                        return Err(Error::new_spanned(
+                            arg,
+                            "Each configuration only accepts with functions",
+                        ))
+                    }
+                }
             }
+            conversion.set_collection(func, Collection::Each)?;
+        }
+        func => {
+            return Err(unknown_error(
+                func,
+                "field call",
+                &func.to_token_stream().to_string(),
+                &CALLS,
+            ))
         }
-        _ => (),
     }
     Ok(())
 }
 
+/// Parse `with(strategy) = function`, other calls are unknown configurations
 fn parse_with_strategy(
-    func: ExprPath,
+    func: &Expr,
     args: &Punctuated<Expr, Comma>,
     value: &syn::Expr,
-    with: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
+    span: Span,
+    with: &mut OrderedSet<SpannedItem<With, MappingStrategy>>,
 ) -> syn::Result<()> {
-    if func.path.is_ident("with") {
-        if args.len() != 1 {
-            Err(Error::new_spanned(
-                args,
-                "Cannot specify more than on strategy (into or mapper) by with config",
-            ))
-        } else if let Expr::Path(strategy) = &args[0] {
-            let strategy =
-                MappingStrategy::try_from(strategy.path.get_ident().unwrap().to_string().as_ref())
-                    .map_err(|e| Error::new_spanned(strategy, e))?;
-            parse_with_value(value, with, Some(strategy))
-        } else {
-            Err(Error::new_spanned(
-                &args[0],
-                "With strategy should be an expression path",
-            ))
+    match func {
+        Expr::Path(func) if func.path.is_ident("with") => {
+            if args.len() != 1 {
+                Err(Error::new_spanned(
+                    args,
+                    "Cannot specify more than on strategy (into or mapper) by with config",
+                ))
+            } else if let Expr::Path(strategy) = &args[0] {
+                let strategy =
+                    MappingStrategy::try_from(strategy.path.to_token_stream().to_string().as_ref())
+                        .map_err(|e| Error::new_spanned(strategy, e))?;
+                parse_with_value(value, span, with, Some(strategy))
+            } else {
+                Err(Error::new_spanned(
+                    &args[0],
+                    "With strategy should be an expression path",
+                ))
+            }
         }
-    } else {
-        Ok(())
+        func => Err(unknown_error(
+            func,
+            "field configuration",
+            &func.to_token_stream().to_string(),
+            &["with"],
+        )),
     }
 }
 
+/// Parse with function path, span of the with configuration is kept to span the generated call
 fn parse_with_value(
     value: &syn::Expr,
-    with: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
+    span: Span,
+    with: &mut OrderedSet<SpannedItem<With, MappingStrategy>>,
     strategy: Option<MappingStrategy>,
 ) -> syn::Result<()> {
     if let Expr::Path(with_fn) = value {
-        let new_with = SpannedItem::new(with_fn.path.clone(), strategy.unwrap_or_default());
+        let new_with = SpannedItem::new(
+            With::new(with_fn.path.clone(), span),
+            strategy.unwrap_or_default(),
+        );
         insert_with(with, new_with, value)
     } else {
         Err(Error::new_spanned(
@@ -192,8 +479,8 @@ fn parse_with_value(
 }
 
 fn insert_with(
-    with: &mut HashSet<SpannedItem<Path, MappingStrategy>>,
-    new_with: SpannedItem<Path, MappingStrategy>,
+    with: &mut OrderedSet<SpannedItem<With, MappingStrategy>>,
+    new_with: SpannedItem<With, MappingStrategy>,
     with_fn: &Expr,
 ) -> syn::Result<()> {
     if with.contains(&new_with) {
@@ -212,17 +499,58 @@ fn insert_with(
 
 fn parse_flag(
     expr_path: ExprPath,
-    exclude: &mut Option<SpannedItem<Path, bool>>,
+    exclude: &mut Exclude,
+    conversion: &mut Conversion,
 ) -> syn::Result<()> {
     if expr_path.path.is_ident("exclude") {
-        if exclude.is_none() {
-            *exclude = Some(SpannedItem::new(expr_path.path, true));
-        } else {
+        exclude.set(expr_path.path)?;
+    } else if expr_path.path.is_ident("direct") {
+        if conversion.direct {
             return Err(Error::new_spanned(
                 expr_path,
-                "Cannot specify multiple time exclude flag",
+                "Cannot specify multiple time direct flag",
             ));
         }
+        conversion.direct = true;
+    } else if expr_path.path.is_ident("unwrap_newtype") {
+        if conversion.unwrap_newtype {
+            return Err(Error::new_spanned(
+                expr_path,
+                "Cannot specify multiple time unwrap_newtype flag",
+            ));
+        }
+        conversion.unwrap_newtype = true;
+    } else if expr_path.path.is_ident("values") {
+        conversion.set_collection(&expr_path, Collection::Values)?;
+    } else if expr_path.path.is_ident("each") {
+        conversion.set_collection(&expr_path, Collection::Each)?;
+    } else if let Some(ident) = expr_path.path.get_ident() {
+        if let Ok(new_pointer) = Pointer::try_from(ident.to_string().as_ref()) {
+            if let Some(pointer) = &conversion.pointer {
+                return Err(Error::new_spanned(
+                    expr_path,
+                    format!(
+                        "Cannot specify multiple pointer flags, ({}) is already specified",
+                        pointer
+                    ),
+                ));
+            }
+            conversion.pointer = Some(new_pointer);
+        } else {
+            return Err(unknown_error(
+                &expr_path,
+                "field flag",
+                &ident.to_string(),
+                &FLAGS,
+            ));
+        }
+    } else {
+        return Err(unknown_error(
+            &expr_path,
+            "field flag",
+            &expr_path.to_token_stream().to_string(),
+            &FLAGS,
+        ));
     }
     Ok(())
 }
@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Result, Token, Type, TypePath,
+};
+
+/// Destination targeted by a field attribute, wildcard is resolved from the struct destinations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Target {
+    Type(TypePath),
+    /// `*`, every destination declared on the struct
+    Wildcard(Token![*]),
+}
+
+impl Parse for Target {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![*]) {
+            Ok(Target::Wildcard(input.parse()?))
+        } else if let Type::Path(ty) = input.parse::<Type>()? {
+            Ok(Target::Type(ty))
+        } else {
+            Err(Error::new(
+                input.span(),
+                "To field attribute destination should be a type path or a wildcard",
+            ))
+        }
+    }
+}
+
+impl ToTokens for Target {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Target::Type(ty) => ty.to_tokens(tokens),
+            Target::Wildcard(wildcard) => wildcard.to_tokens(tokens),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{spanned::Spanned, Path};
+
+/// Function transforming a field, its generated call is spanned on the whole `with = ...` configuration
+#[derive(Clone, Debug)]
+pub struct With {
+    pub path: Path,
+    pub span: Span,
+}
+
+impl With {
+    pub fn new(path: Path, span: Span) -> Self {
+        With { path, span }
+    }
+}
+
+impl From<Path> for With {
+    fn from(path: Path) -> Self {
+        let span = path.span();
+        With { path, span }
+    }
+}
+
+impl ToTokens for With {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens)
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Display;
+
+/// Define how fields without with function are converted to the destination field
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+pub enum MappingMode {
+    /// Fields are cloned by mapper strategy or moved by into strategy
+    Direct,
+    /// Fields are converted through mapper_api::FieldConvert
+    Convert,
+    /// Fields are mapped through mapper_api::Mapper by mapper strategy or Into by into strategy
+    Deep,
+}
+
+impl Display for MappingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MappingMode::Direct => write!(f, "direct"),
+            MappingMode::Convert => write!(f, "convert"),
+            MappingMode::Deep => write!(f, "deep"),
+        }
+    }
+}
+
+impl Default for MappingMode {
+    fn default() -> Self {
+        MappingMode::Direct
+    }
+}
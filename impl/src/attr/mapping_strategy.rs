@@ -1,4 +1,6 @@
-use std::{collections::HashSet, fmt::Display};
+use std::fmt::Display;
+
+use crate::common::ordered_set::OrderedSet;
 
 use syn::{Error, Path, Result as SynResult};
 use thiserror::Error;
@@ -12,15 +14,13 @@ pub enum MappingStrategy {
 }
 
 impl MappingStrategy {
-    pub fn str_to_hash_set(
-        val: &str,
-    ) -> Result<HashSet<MappingStrategy>, MappingStrategyParseError> {
+    pub fn str_to_set(val: &str) -> Result<OrderedSet<MappingStrategy>, MappingStrategyParseError> {
         match val {
             "all" => Ok([MappingStrategy::Into, MappingStrategy::Mapper]
                 .into_iter()
                 .collect()),
             _val => {
-                let mut set = HashSet::new();
+                let mut set = OrderedSet::new();
                 set.insert(MappingStrategy::try_from(_val)?);
                 Ok(set)
             }
@@ -62,8 +62,8 @@ pub const MAX_STRATEGIES_BY_ATTRIBUTE: usize = 2;
 
 pub fn parse_strategy(
     path: &Path,
-    strategies: &HashSet<SpannedItem<Path, MappingStrategy>>,
-) -> SynResult<HashSet<SpannedItem<Path, MappingStrategy>>> {
+    strategies: &OrderedSet<SpannedItem<Path, MappingStrategy>>,
+) -> SynResult<OrderedSet<SpannedItem<Path, MappingStrategy>>> {
     if strategies.len() >= MAX_STRATEGIES_BY_ATTRIBUTE {
         Err(Error::new_spanned(
             path,
@@ -73,7 +73,7 @@ pub fn parse_strategy(
         let ident = path
             .get_ident()
             .ok_or_else(|| Error::new_spanned(path, "Invalid strategy"))?;
-        let strategies = MappingStrategy::str_to_hash_set(ident.to_string().as_ref())
+        let strategies = MappingStrategy::str_to_set(ident.to_string().as_ref())
             .map_err(|e| Error::new_spanned(path, e))?;
         Ok(strategies
             .into_iter()
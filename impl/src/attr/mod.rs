@@ -1,7 +1,11 @@
 pub mod aggregated_to;
 pub mod attrs;
+pub mod container;
 pub mod data_type;
 pub mod field;
+pub mod mapping_mode;
 pub mod mapping_strategy;
+pub mod pointer;
+pub mod rename_rule;
 pub mod spanned_item;
 pub mod to;
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+pub enum Pointer {
+    Box,
+    Rc,
+    Arc,
+    Deref,
+}
+
+impl Display for Pointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pointer::Box => write!(f, "boxed"),
+            Pointer::Rc => write!(f, "rc"),
+            Pointer::Arc => write!(f, "arc"),
+            Pointer::Deref => write!(f, "deref"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Invalid pointer {0}, available values : [boxed, rc, arc, deref]")]
+pub struct PointerParseError(String);
+
+impl TryFrom<&str> for Pointer {
+    type Error = PointerParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "boxed" => Ok(Pointer::Box),
+            "rc" => Ok(Pointer::Rc),
+            "arc" => Ok(Pointer::Arc),
+            "deref" => Ok(Pointer::Deref),
+            _ => Err(PointerParseError(value.to_string())),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+/// Case conversion applied to source field names to compute automatic destination field names
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+        }
+    }
+}
+
+/// Split a name on underscores and on lower to upper case boundaries
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_is_lower = false;
+    for c in name.chars() {
+        let is_boundary = c == '_' || (c.is_uppercase() && previous_is_lower);
+        if is_boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if c != '_' {
+            word.push(c);
+        }
+        previous_is_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+impl Display for RenameRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenameRule::Lower => write!(f, "lowercase"),
+            RenameRule::Upper => write!(f, "UPPERCASE"),
+            RenameRule::Pascal => write!(f, "PascalCase"),
+            RenameRule::Camel => write!(f, "camelCase"),
+            RenameRule::Snake => write!(f, "snake_case"),
+            RenameRule::ScreamingSnake => write!(f, "SCREAMING_SNAKE_CASE"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Invalid rename rule {0}, available values : [lowercase, UPPERCASE, PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE]")]
+pub struct RenameRuleParseError(String);
+
+impl TryFrom<&str> for RenameRule {
+    type Error = RenameRuleParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            _ => Err(RenameRuleParseError(value.to_string())),
+        }
+    }
+}
@@ -5,8 +5,11 @@ use std::{
 
 use quote::spanned::Spanned;
 
-pub trait SpannedItemSpan = Spanned + Debug + Clone;
-pub trait SpannedItemItem = Hash + Eq + PartialEq + Debug + Clone + Display + Default;
+pub trait SpannedItemSpan: Spanned + Debug + Clone {}
+impl<T: Spanned + Debug + Clone> SpannedItemSpan for T {}
+
+pub trait SpannedItemItem: Hash + Eq + PartialEq + Debug + Clone + Display + Default {}
+impl<T: Hash + Eq + PartialEq + Debug + Clone + Display + Default> SpannedItemItem for T {}
 
 #[derive(Debug, Clone, Eq)]
 pub struct SpannedItem<Span: SpannedItemSpan, Item: SpannedItemItem>(pub Option<Span>, pub Item);
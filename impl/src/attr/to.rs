@@ -1,4 +1,13 @@
-use syn::{parse::Parse, Attribute, Result};
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream, Parser},
+    token, Attribute, Error, Result, Token,
+};
+
+mod kw {
+    syn::custom_keyword!(to);
+}
 
 #[derive(Clone, Debug)]
 pub struct To<'a, T: Parse> {
@@ -6,11 +15,57 @@ pub struct To<'a, T: Parse> {
     pub params: T,
 }
 impl<'a, T: Parse> To<'a, T> {
-    pub fn new(attr: &'a Attribute) -> Result<To<'a, T>> {
-        let to = To {
+    pub fn new_with<F: FnOnce(ParseStream) -> Result<T>>(
+        attr: &'a Attribute,
+        parser: F,
+    ) -> Result<To<'a, T>> {
+        Ok(To {
             original: attr,
-            params: attr.parse_args_with(T::parse)?,
-        };
-        Ok(to)
+            params: attr.parse_args_with(parser)?,
+        })
+    }
+    /// Parse mapping configurations of `#[to(...)]`, `#[mapper_to(...)]` and `to(...)` items of `#[mapper(...)]` attributes,
+    /// other items of mapper attribute are skipped if `skip_other_items` is set,
+    /// `#[to(...)]` attributes are left to other derives if `namespaced` is set
+    pub fn from_attribute<F: Fn(ParseStream) -> Result<T>>(
+        attr: &'a Attribute,
+        parser: F,
+        skip_other_items: bool,
+        namespaced: bool,
+    ) -> Result<Vec<To<'a, T>>> {
+        if (attr.path.is_ident("to") && !namespaced) || attr.path.is_ident("mapper_to") {
+            Ok(vec![To::new_with(attr, parser)?])
+        } else if attr.path.is_ident("mapper") {
+            attr.parse_args_with(|input: ParseStream| {
+                let mut to_items = Vec::new();
+                while !input.is_empty() {
+                    if input.peek(kw::to) && input.peek2(token::Paren) {
+                        input.parse::<kw::to>()?;
+                        let content;
+                        parenthesized!(content in input);
+                        let tokens = content.parse::<TokenStream>()?;
+                        to_items.push(To {
+                            original: attr,
+                            params: (&parser).parse2(tokens)?,
+                        });
+                    } else if skip_other_items {
+                        while !input.is_empty() && !input.peek(Token![,]) {
+                            input.parse::<TokenTree>()?;
+                        }
+                    } else {
+                        return Err(Error::new(
+                            input.span(),
+                            "Mapper field attribute only accepts to configurations",
+                        ));
+                    }
+                    if !input.is_empty() {
+                        input.parse::<Token![,]>()?;
+                    }
+                }
+                Ok(to_items)
+            })
+        } else {
+            Ok(vec![])
+        }
     }
 }
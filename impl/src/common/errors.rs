@@ -0,0 +1,35 @@
+use syn::{Error, Result};
+
+/// Accumulate errors to report all of them at once
+#[derive(Debug, Default)]
+pub struct Errors {
+    error: Option<Error>,
+}
+
+impl Errors {
+    pub fn push(&mut self, error: Error) {
+        match &mut self.error {
+            Some(current) => current.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+    /// Keep result error and return result value
+    pub fn capture<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+    pub fn into_error(self) -> Option<Error> {
+        self.error
+    }
+    pub fn finish<T>(self, value: T) -> Result<T> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(value),
+        }
+    }
+}
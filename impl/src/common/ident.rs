@@ -0,0 +1,21 @@
+use proc_macro2::{Ident, Span};
+
+/// Parse an identifier from its name, keywords are escaped as raw identifiers
+pub fn parse_ident(name: &str, span: Span) -> Option<Ident> {
+    syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)))
+        .ok()
+        .map(|mut ident| {
+            ident.set_span(span);
+            ident
+        })
+}
+
+const PRIMITIVES: [&str; 17] = [
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+pub fn is_primitive(name: &str) -> bool {
+    PRIMITIVES.contains(&name)
+}
@@ -1 +1,5 @@
+pub mod errors;
+pub mod ident;
+pub mod ordered_set;
 pub mod punctuated_extensions;
+pub mod suggestion;
@@ -0,0 +1,105 @@
+use std::{iter::FromIterator, slice::Iter, vec::IntoIter};
+
+/// Set keeping insertion order, used for items whose iteration order ends up in generated code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderedSet<T> {
+    items: Vec<T>,
+}
+
+impl<T: PartialEq> OrderedSet<T> {
+    pub fn new() -> Self {
+        OrderedSet { items: Vec::new() }
+    }
+    /// Add an item at the end, returns false when an equal item is already present
+    pub fn insert(&mut self, item: T) -> bool {
+        if self.contains(&item) {
+            false
+        } else {
+            self.items.push(item);
+            true
+        }
+    }
+    /// Replace an equal item at its position or add the item at the end
+    pub fn replace(&mut self, item: T) -> Option<T> {
+        match self.items.iter().position(|current| current == &item) {
+            Some(index) => Some(std::mem::replace(&mut self.items[index], item)),
+            None => {
+                self.items.push(item);
+                None
+            }
+        }
+    }
+    pub fn get(&self, item: &T) -> Option<&T> {
+        self.items.iter().find(|&current| current == item)
+    }
+    pub fn get_or_insert(&mut self, item: T) -> &T {
+        let index = match self.items.iter().position(|current| current == &item) {
+            Some(index) => index,
+            None => {
+                self.items.push(item);
+                self.items.len() - 1
+            }
+        };
+        &self.items[index]
+    }
+    pub fn remove(&mut self, item: &T) -> bool {
+        let len = self.items.len();
+        self.items.retain(|current| current != item);
+        self.items.len() != len
+    }
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.items.retain(f);
+    }
+    pub fn contains(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.items.iter()
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T: PartialEq> Default for OrderedSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> Extend<T> for OrderedSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: PartialEq> FromIterator<T> for OrderedSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OrderedSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> IntoIterator for OrderedSet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OrderedSet<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
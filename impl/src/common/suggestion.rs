@@ -0,0 +1,41 @@
+use quote::ToTokens;
+use syn::Error;
+
+/// Spanned error for an unknown name listing available values with the closest one as suggestion
+pub fn unknown_error<T: ToTokens>(tokens: T, kind: &str, name: &str, options: &[&str]) -> Error {
+    let mut message = format!(
+        "Unknown {} ({}), available values : [{}]",
+        kind,
+        name,
+        options.join(", ")
+    );
+    if let Some(suggestion) = suggest(name, options) {
+        message.push_str(&format!(", did you mean ({}) ?", suggestion));
+    }
+    Error::new_spanned(tokens, message)
+}
+
+/// Closest option within a few edits of name, names farther from every option aren't considered as typos
+pub fn suggest<'a>(name: &str, options: &[&'a str]) -> Option<&'a str> {
+    let max_distance = std::cmp::max(2, name.chars().count() / 3);
+    options
+        .iter()
+        .map(|option| (levenshtein(name, option), *option))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, option)| option)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous = (0..=b.len()).collect::<Vec<usize>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
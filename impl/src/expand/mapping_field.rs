@@ -1,7 +1,23 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
+use syn::{Expr, Index, Member, Path, Type, TypeGroup, TypeParen};
 
-use crate::{ast::mapping_field::MappingField, attr::mapping_strategy::MappingStrategy};
+use crate::{
+    ast::mapping_field::MappingField,
+    attr::{
+        field::conversion::Collection, mapping_mode::MappingMode,
+        mapping_strategy::MappingStrategy, pointer::Pointer,
+    },
+};
+
+/// Shape of the source field used to map it element by element
+enum Elements<'a> {
+    /// Fixed size array with its length expression
+    Array(&'a Expr),
+    Tuple(usize),
+    /// Any iterable collection, true when the field is a reference
+    Iter(bool),
+}
 
 impl MappingField {
     pub fn get_dest_field(&self) -> TokenStream {
@@ -11,18 +27,180 @@ impl MappingField {
             (&self.member).into_token_stream()
         }
     }
-    pub fn get_src_field(&self) -> TokenStream {
+    pub fn get_src_field(&self, krate: &Path) -> TokenStream {
         let src = &self.member;
+        let is_deref = self.conversion.pointer == Some(Pointer::Deref);
+        let mut place = quote::quote_spanned!(self.span=> self.#src);
+        if is_deref {
+            place = quote::quote_spanned!(self.span=> (*#place));
+        }
+        if self.conversion.unwrap_newtype {
+            let inner = Member::Unnamed(Index::from(0));
+            place = quote::quote_spanned!(self.span=> #place.#inner);
+        }
+        let value = match self.strategy {
+            MappingStrategy::Into => {
+                let owned = if is_deref {
+                    quote::quote_spanned!(self.span=> ::core::clone::Clone::clone(&#place))
+                } else {
+                    place
+                };
+                self.convert_owned_collection(owned, krate)
+            }
+            MappingStrategy::Mapper => self.convert_borrowed_collection(place, krate),
+        };
+        self.wrap(value, krate)
+    }
+    fn convert_borrowed_collection(&self, place: TokenStream, krate: &Path) -> TokenStream {
+        let span = expansion_span(self.span);
+        let item = self.convert_borrowed(quote::quote_spanned!(self.span=> (*item)), krate);
+        match &self.conversion.collection {
+            Some(Collection::Each) => match self.get_elements() {
+                Elements::Array(len) => quote::quote_spanned! {span=>
+                    {
+                        let mut index = 0;
+                        [(); #len].map(|()| {
+                            let item = &#place[index];
+                            index += 1;
+                            #item
+                        })
+                    }
+                },
+                Elements::Tuple(len) => {
+                    let items = (0..len).map(|i| {
+                        let index = Index::from(i);
+                        self.convert_borrowed(
+                            quote::quote_spanned!(self.span=> #place.#index),
+                            krate,
+                        )
+                    });
+                    quote::quote_spanned! {self.span=> (#(#items,)*)}
+                }
+                Elements::Iter(_) => quote::quote_spanned! {span=>
+                    #place.iter().map(|item| #item).collect()
+                },
+            },
+            Some(Collection::ByKey(key)) => quote::quote_spanned! {span=>
+                #place.iter().map(|item| (::core::clone::Clone::clone(&item.#key), #item)).collect()
+            },
+            Some(Collection::Values) => quote::quote_spanned! {span=>
+                #place.values().map(|item| #item).collect()
+            },
+            None => self.convert_borrowed(place, krate),
+        }
+    }
+    fn convert_owned_collection(&self, value: TokenStream, krate: &Path) -> TokenStream {
+        let span = expansion_span(self.span);
+        let item = self.convert_owned(quote::quote_spanned!(self.span=> item), krate);
+        match &self.conversion.collection {
+            Some(Collection::Each) => match self.get_elements() {
+                Elements::Array(_) => quote::quote_spanned! {span=> #value.map(|item| #item)},
+                Elements::Tuple(len) => {
+                    let items = (0..len).map(|i| {
+                        let index = Index::from(i);
+                        self.convert_owned(quote::quote_spanned!(self.span=> #value.#index), krate)
+                    });
+                    quote::quote_spanned! {self.span=> (#(#items,)*)}
+                }
+                Elements::Iter(true) => {
+                    let item =
+                        self.convert_borrowed(quote::quote_spanned!(self.span=> (*item)), krate);
+                    quote::quote_spanned! {span=> #value.iter().map(|item| #item).collect()}
+                }
+                Elements::Iter(false) => quote::quote_spanned! {span=>
+                    #value.into_iter().map(|item| #item).collect()
+                },
+            },
+            Some(Collection::ByKey(key)) => quote::quote_spanned! {span=>
+                #value.into_iter().map(|item| (::core::clone::Clone::clone(&item.#key), #item)).collect()
+            },
+            Some(Collection::Values) => quote::quote_spanned! {span=>
+                #value.into_iter().map(|(_, item)| #item).collect()
+            },
+            None => self.convert_owned(value, krate),
+        }
+    }
+    fn get_elements(&self) -> Elements<'_> {
+        if self.conversion.pointer == Some(Pointer::Deref) || self.conversion.unwrap_newtype {
+            return Elements::Iter(false);
+        }
+        let mut ty = &self.ty;
+        while let Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) = ty {
+            ty = elem;
+        }
+        match ty {
+            Type::Array(array) => Elements::Array(&array.len),
+            Type::Tuple(tuple) => Elements::Tuple(tuple.elems.len()),
+            Type::Reference(_) => Elements::Iter(true),
+            _ => Elements::Iter(false),
+        }
+    }
+    fn convert_borrowed(&self, place: TokenStream, krate: &Path) -> TokenStream {
         if let Some(with) = &self.with {
-            match self.strategy {
-                MappingStrategy::Into => quote::quote!(#with(self.#src)),
-                MappingStrategy::Mapper => quote::quote! {#with(&self.#src)},
+            quote::quote_spanned! {with.span=> #with(&#place)}
+        } else {
+            match self.get_mode() {
+                MappingMode::Direct => {
+                    quote::quote_spanned! {self.span=> ::core::clone::Clone::clone(&#place)}
+                }
+                MappingMode::Convert => {
+                    quote::quote_spanned! {self.span=> #krate::FieldConvert::convert(&#place)}
+                }
+                MappingMode::Deep => match self.strategy {
+                    MappingStrategy::Mapper => {
+                        quote::quote_spanned! {self.span=> #krate::Mapper::to(&#place)}
+                    }
+                    MappingStrategy::Into => {
+                        quote::quote_spanned! {self.span=> ::core::convert::Into::into(::core::clone::Clone::clone(&#place))}
+                    }
+                },
             }
+        }
+    }
+    fn convert_owned(&self, value: TokenStream, krate: &Path) -> TokenStream {
+        if let Some(with) = &self.with {
+            quote::quote_spanned!(with.span=> #with(#value))
+        } else {
+            match self.get_mode() {
+                MappingMode::Direct => value,
+                MappingMode::Convert => {
+                    quote::quote_spanned! {self.span=> #krate::FieldConvert::convert(&#value)}
+                }
+                MappingMode::Deep => {
+                    quote::quote_spanned! {self.span=> ::core::convert::Into::into(#value)}
+                }
+            }
+        }
+    }
+    fn get_mode(&self) -> &MappingMode {
+        if self.conversion.direct {
+            &MappingMode::Direct
         } else {
-            match self.strategy {
-                MappingStrategy::Into => quote::quote!(self.#src),
-                MappingStrategy::Mapper => quote::quote! {self.#src.clone()},
+            &self.mode
+        }
+    }
+    fn wrap(&self, value: TokenStream, krate: &Path) -> TokenStream {
+        let value = if let Some(newtype) = &self.conversion.wrap_newtype {
+            quote::quote_spanned! {self.span=> #newtype(#value)}
+        } else {
+            value
+        };
+        match self.conversion.pointer {
+            Some(Pointer::Box) => {
+                quote::quote_spanned! {self.span=> #krate::__private::Box::new(#value)}
+            }
+            Some(Pointer::Rc) => {
+                quote::quote_spanned! {self.span=> #krate::__private::Rc::new(#value)}
             }
+            Some(Pointer::Arc) => {
+                quote::quote_spanned! {self.span=> #krate::__private::Arc::new(#value)}
+            }
+            Some(Pointer::Deref) | None => value,
         }
     }
 }
+
+/// Span located at user code but marked as generated code, clippy lints don't apply to generated closures
+fn expansion_span(span: Span) -> Span {
+    Span::call_site().located_at(span)
+}
@@ -1,34 +1,43 @@
 use quote::ToTokens;
+use syn::Path;
 
 use crate::{ast::mapping_tree::MappingTree, attr::mapping_strategy::MappingStrategy};
 
 impl MappingTree {
-    pub fn expand(&self, tokens: &mut proc_macro2::TokenStream) {
+    pub fn expand(&self, tokens: &mut proc_macro2::TokenStream, krate: &Path) {
         let dest = &self.destination;
         let ty = &self.ident;
-        let fields = self.mapping_fields.iter().map(|f| {
-            let destination = f.get_dest_field();
-            let value = f.get_src_field();
+        let body = if self.transparent {
+            let value = self.mapping_fields.iter().map(|f| f.get_src_field(krate));
             quote::quote! {
-                #destination:#value
+                #(#value)*
             }
-        });
+        } else {
+            let fields = self.mapping_fields.iter().map(|f| {
+                let destination = f.get_dest_field();
+                let value = f.get_src_field(krate);
+                quote::quote_spanned! {f.span=>
+                    #destination:#value
+                }
+            });
+            quote::quote! {
+                #dest{
+                    #(#fields),*
+                }
+            }
+        };
         let implementation = match self.strategy {
             MappingStrategy::Into => quote::quote! {
-                impl Into<#dest> for #ty{
+                impl ::core::convert::Into<#dest> for #ty{
                     fn into(self) -> #dest{
-                        #dest{
-                            #(#fields),*
-                        }
+                        #body
                     }
                 }
             },
             MappingStrategy::Mapper => quote::quote! {
-                impl Mapper<#dest> for #ty {
+                impl #krate::Mapper<#dest> for #ty {
                     fn to(&self)->#dest{
-                        #dest{
-                            #(#fields),*
-                        }
+                        #body
                     }
                 }
             },
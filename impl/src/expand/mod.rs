@@ -1,7 +1,7 @@
 pub mod mapping_field;
 pub mod mapping_tree;
 
-use std::collections::HashSet;
+use crate::common::ordered_set::OrderedSet;
 
 use proc_macro2::TokenStream;
 
@@ -11,21 +11,31 @@ use syn::Result;
 use crate::ast::data_type::Struct;
 use crate::ast::mapping_tree::MappingTree;
 use crate::ast::Input;
+use crate::valid::Validation;
 
+/// Expand mapping trees of valid destinations and report attribute and validation errors alongside
 pub fn derive(node: &DeriveInput) -> Result<TokenStream> {
-    let input = Input::from_syn(node)?;
-    input.validate()?;
-    Ok(match input {
-        Input::Struct(input) => impl_struct(input),
-    })
+    let mut validation = Validation::default();
+    let input = Input::from_syn(node, &mut validation)?;
+    input.validate(&mut validation);
+    let mut token_stream = match input {
+        Input::Struct(input) => impl_struct(input, &validation),
+    };
+    if let Some(error) = validation.into_error() {
+        token_stream.extend(error.to_compile_error());
+    }
+    Ok(token_stream)
 }
 
-fn impl_struct(input: Struct) -> TokenStream {
-    let mapping_trees = HashSet::<MappingTree>::from(input.clone());
+fn impl_struct(input: Struct, validation: &Validation) -> TokenStream {
+    let krate = input.container.get_crate_path();
+    let mapping_trees = OrderedSet::<MappingTree>::from(input.clone());
     let (_impl_generics, _ty_generics, _where_clause) = input.generics.split_for_impl();
     let mut token_stream = TokenStream::new();
     for mapping_tree in mapping_trees {
-        mapping_tree.expand(&mut token_stream)
+        if validation.is_valid_destination(&mapping_tree.destination) {
+            mapping_tree.expand(&mut token_stream, &krate)
+        }
     }
     token_stream
 }
@@ -1,6 +1,3 @@
-#![feature(trait_alias)]
-#![feature(hash_set_entry)]
-
 mod ast;
 mod attr;
 mod common;
@@ -11,7 +8,7 @@ extern crate proc_macro;
 use syn::parse_macro_input;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Mapper, attributes(to))]
+#[proc_macro_derive(Mapper, attributes(to, mapper, mapper_to))]
 pub fn derive_mapper(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     expand::derive(&input)
@@ -1,16 +1,88 @@
-use syn::{Error, Result};
+use quote::ToTokens;
+use std::collections::{HashMap, HashSet};
+
+use syn::{ext::IdentExt, Error, Member, Type, TypeGroup, TypeParen, TypePath};
+
+use crate::{
+    ast::{data_type::Struct, mapping_field::MappingField, mapping_tree::MappingTree, Input},
+    attr::{
+        field::conversion::{Collection, Conversion},
+        mapping_strategy::MappingStrategy,
+        pointer::Pointer,
+    },
+    common::{errors::Errors, ordered_set::OrderedSet},
+};
+
+/// Validation errors and destinations they invalidate, mapping trees of valid destinations are still expanded
+#[derive(Debug, Default)]
+pub struct Validation {
+    errors: Errors,
+    invalid_destinations: HashSet<TypePath>,
+    /// Destinations of attributes which couldn't be parsed, their other errors could come from the missing attribute
+    unparsed_destinations: HashSet<TypePath>,
+    /// Errors without destination invalidate the whole struct
+    is_invalid_struct: bool,
+}
+
+impl Validation {
+    pub fn push<'a>(&mut self, error: Error, destinations: impl IntoIterator<Item = &'a TypePath>) {
+        let destinations = destinations.into_iter().collect::<Vec<&TypePath>>();
+        if !destinations.is_empty()
+            && destinations
+                .iter()
+                .all(|&destination| self.unparsed_destinations.contains(destination))
+        {
+            return;
+        }
+        self.errors.push(error);
+        self.invalid_destinations
+            .extend(destinations.into_iter().cloned());
+    }
+    /// Error of an attribute which couldn't be parsed, the whole struct is invalid without destinations
+    pub fn push_unparsed<'a>(
+        &mut self,
+        error: Error,
+        destinations: impl IntoIterator<Item = &'a TypePath>,
+    ) {
+        let destinations = destinations.into_iter().cloned().collect::<Vec<TypePath>>();
+        self.is_invalid_struct |= destinations.is_empty();
+        self.errors.push(error);
+        self.invalid_destinations
+            .extend(destinations.iter().cloned());
+        self.unparsed_destinations.extend(destinations);
+    }
+    pub fn is_valid_struct(&self) -> bool {
+        !self.is_invalid_struct
+    }
+    pub fn is_valid_destination(&self, destination: &TypePath) -> bool {
+        !self.is_invalid_struct && !self.invalid_destinations.contains(destination)
+    }
+    pub fn into_error(self) -> Option<Error> {
+        self.errors.into_error()
+    }
+}
 
-use crate::ast::{data_type::Struct, Input};
 impl Input<'_> {
-    pub(crate) fn validate(&self) -> Result<()> {
+    pub(crate) fn validate(&self, validation: &mut Validation) {
         match self {
-            Input::Struct(input) => input.validate(),
+            Input::Struct(input) => input.validate(validation),
         }
     }
 }
 
 impl Struct<'_> {
-    fn validate(&self) -> Result<()> {
+    fn validate(&self, validation: &mut Validation) {
+        if !validation.is_valid_struct() {
+            return;
+        }
+        self.validate_transparent(validation);
+        self.validate_rename(validation);
+        let mapping_trees = OrderedSet::<MappingTree>::from(self.clone());
+        self.validate_destination_fields(&mapping_trees, validation);
+        self.validate_unmapped_fields(&mapping_trees, validation);
+        self.validate_exclusions(validation);
+        self.validate_each_tuples(validation);
+        self.validate_conversions(validation);
         for field in &self.fields {
             for field_to in &field.attrs.to.to_items {
                 if field_to.params.destination.is_none() {
@@ -22,39 +94,321 @@ impl Struct<'_> {
                         self.attrs.to.destinations_by_strategy.get(field_strategy)
                     {
                         if struct_destinations.contains(field_dest) {
-                            return Err(Error::new_spanned(
+                            validation.push(Error::new_spanned(
                                 &field_strategy.0,
                                 format!(
                                     "Additive mapping not works for destination ({}) and strategy ({}) because it's already used in automatic mapping", 
-                                    field_dest.path.get_ident().unwrap(),
+                                    field_dest.to_token_stream(),
                                     field_strategy
                             ),
-                            ));
+                            ), Some(field_dest));
                         }
                     }
                 }
-                if field_to.params.exclude.1 && !self.attrs.to.destinations().contains(field_dest) {
-                    return Err(Error::new_spanned(
-                        &field_to.params.exclude.0,
-                         format!(
-                            "Cannot exclude a field for a destination ({}) not referenced in automatic mapping",
-                             field_dest.path.get_ident().unwrap())
-                            ));
-                }
 
                 for field_with in &field_to.params.with {
                     if !self.has_strategy_for_destination(field_dest, &field_with.1) {
-                        return Err(Error::new_spanned(
+                        validation.push(Error::new_spanned(
                             field_with.0.clone(),
                              format!(
                                 "There is no destination and strategy matching with strategy ({}) and destination ({})",
                                 field_with.1,
-                                field_dest.path.get_ident().unwrap()
-                            )));
+                                field_dest.to_token_stream()
+                            )), Some(field_dest));
+                    }
+                }
+            }
+        }
+    }
+    fn validate_transparent(&self, validation: &mut Validation) {
+        for struct_to in &self.attrs.to.to_items {
+            if !struct_to.params.transparent.1 {
+                continue;
+            }
+            let is_newtype =
+                self.fields.len() == 1 && matches!(self.fields[0].member, Member::Unnamed(_));
+            if !is_newtype {
+                validation.push(
+                    Error::new_spanned(
+                        &struct_to.params.transparent.0,
+                        "Transparent mapping requires a tuple struct with a single field",
+                    ),
+                    &struct_to.params.destinations,
+                );
+                continue;
+            }
+            for destination in &struct_to.params.destinations {
+                if self.fields[0].attrs.to.to_items.iter().any(|field_to| {
+                    struct_to
+                        .params
+                        .strategies
+                        .iter()
+                        .any(|strategy| field_to.is_excluded_for(destination, &strategy.1))
+                }) {
+                    validation.push(
+                        Error::new_spanned(
+                            &struct_to.params.transparent.0,
+                            format!(
+                                "Cannot exclude the field of a transparent mapping for destination ({})",
+                                destination.to_token_stream()
+                            ),
+                        ),
+                        Some(destination),
+                    );
+                }
+            }
+        }
+    }
+    fn validate_rename(&self, validation: &mut Validation) {
+        for struct_to in &self.attrs.to.to_items {
+            if struct_to.params.rename.is_empty() {
+                continue;
+            }
+            for destination in &struct_to.params.destinations {
+                for field in &self.fields {
+                    let ident = match &field.member {
+                        Member::Named(ident) => ident,
+                        Member::Unnamed(_) => continue,
+                    };
+                    let is_renamed = !field.attrs.to.to_items.iter().any(|field_to| {
+                        struct_to
+                            .params
+                            .strategies
+                            .iter()
+                            .all(|strategy| field_to.is_excluded_for(destination, &strategy.1))
+                            || (field_to.params.field.is_some()
+                                && field_to.params.destination.as_ref() == Some(destination))
+                    });
+                    if !is_renamed {
+                        continue;
+                    }
+                    if let Err(err) = struct_to.params.rename.apply(&ident.unraw().to_string()) {
+                        validation.push(
+                            Error::new_spanned(
+                                ident,
+                                format!(
+                                    "Rename rules of destination ({}) cannot be applied to field ({}), {}",
+                                    destination.to_token_stream(),
+                                    ident,
+                                    err
+                                ),
+                            ),
+                            Some(destination),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    /// Each destination field should be targeted by only one source field
+    fn validate_destination_fields(
+        &self,
+        mapping_trees: &OrderedSet<MappingTree>,
+        validation: &mut Validation,
+    ) {
+        let mut reported = HashSet::new();
+        for mapping_tree in mapping_trees {
+            if mapping_tree.transparent {
+                continue;
+            }
+            let mut targets: HashMap<String, &MappingField> = HashMap::new();
+            for mapping_field in &mapping_tree.mapping_fields {
+                let target = mapping_field.get_dest_field().to_string();
+                if let Some(first) = targets.get(&target) {
+                    let destination = mapping_tree.destination.to_token_stream().to_string();
+                    if !reported.insert((destination.clone(), target.clone())) {
+                        continue;
                     }
+                    let message = format!(
+                        "Destination field ({}) of destination ({}) is targeted by multiple fields",
+                        target, destination
+                    );
+                    let mut error = Error::new(first.span, &message);
+                    error.combine(Error::new(mapping_field.span, &message));
+                    validation.push(error, Some(&mapping_tree.destination));
+                    continue;
+                }
+                targets.insert(target, mapping_field);
+            }
+        }
+    }
+    /// Source fields should be mapped or excluded for destinations denying unmapped fields
+    fn validate_unmapped_fields(
+        &self,
+        mapping_trees: &OrderedSet<MappingTree>,
+        validation: &mut Validation,
+    ) {
+        let mut reported = HashSet::new();
+        for mapping_tree in mapping_trees {
+            if !mapping_tree.deny_unmapped || mapping_tree.transparent {
+                continue;
+            }
+            for field in &self.fields {
+                let is_mapped = mapping_tree
+                    .mapping_fields
+                    .iter()
+                    .any(|mapping_field| mapping_field.member == field.member);
+                let is_excluded = field.attrs.to.to_items.iter().any(|field_to| {
+                    field_to.is_excluded_for(&mapping_tree.destination, &mapping_tree.strategy)
+                });
+                if is_mapped || is_excluded {
+                    continue;
+                }
+                let member = field.member.to_token_stream().to_string();
+                let destination = mapping_tree.destination.to_token_stream().to_string();
+                if !reported.insert((member.clone(), destination.clone())) {
+                    continue;
+                }
+                validation.push(
+                    Error::new(
+                        field.span(),
+                        format!(
+                            "Field ({}) is neither mapped nor excluded for destination ({}) and strategy ({}), unmapped fields are denied",
+                            member, destination, mapping_tree.strategy
+                        ),
+                    ),
+                    Some(&mapping_tree.destination),
+                );
+            }
+        }
+    }
+    /// Excluded strategies should be mapped for the destination and the field shouldn't be mapped with them
+    fn validate_exclusions(&self, validation: &mut Validation) {
+        for field in &self.fields {
+            for field_to in &field.attrs.to.to_items {
+                let (exclude_path, destination) =
+                    match (&field_to.params.exclude.path, &field_to.params.destination) {
+                        (Some(path), Some(destination)) => (path, destination),
+                        _ => continue,
+                    };
+                let strategies = [MappingStrategy::Into, MappingStrategy::Mapper]
+                    .into_iter()
+                    .filter(|strategy| field_to.params.exclude.has_strategy(strategy))
+                    .collect::<Vec<MappingStrategy>>();
+                let mapped_strategies = strategies
+                    .iter()
+                    .filter(|&strategy| self.has_strategy_for_destination(destination, strategy))
+                    .collect::<Vec<&MappingStrategy>>();
+                let unmapped_strategies = strategies
+                    .iter()
+                    .filter(|&strategy| !mapped_strategies.contains(&strategy))
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>();
+                if mapped_strategies.is_empty() && field_to.params.exclude.strategies.is_empty() {
+                    validation.push(
+                        Error::new_spanned(
+                            exclude_path,
+                            format!(
+                                "Cannot exclude a field for a destination ({}) without mapping",
+                                destination.to_token_stream()
+                            ),
+                        ),
+                        Some(destination),
+                    );
+                } else if !field_to.params.exclude.strategies.is_empty()
+                    && !unmapped_strategies.is_empty()
+                {
+                    validation.push(
+                        Error::new_spanned(
+                            exclude_path,
+                            format!(
+                                "Cannot exclude a field for a destination ({}) and strategy ({}) without mapping",
+                                destination.to_token_stream(),
+                                unmapped_strategies.join(",")
+                            ),
+                        ),
+                        Some(destination),
+                    );
+                }
+                for strategy in &strategies {
+                    let is_mapped = field.attrs.to.to_items.iter().any(|other_to| {
+                        other_to
+                            .is_additive_mapping_for_destination_and_strategy(destination, strategy)
+                    });
+                    if is_mapped {
+                        validation.push(
+                            Error::new_spanned(
+                                exclude_path,
+                                format!(
+                                    "Cannot map and exclude field ({}) for destination ({}) and strategy ({})",
+                                    field.member.to_token_stream(),
+                                    destination.to_token_stream(),
+                                    strategy
+                                ),
+                            ),
+                            Some(destination),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    /// A with function is applied to every component of a tuple, components should have the same type
+    fn validate_each_tuples(&self, validation: &mut Validation) {
+        for field in &self.fields {
+            let mut ty = field.ty;
+            while let Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) = ty
+            {
+                ty = elem;
+            }
+            let tuple = match ty {
+                Type::Tuple(tuple) => tuple,
+                _ => continue,
+            };
+            if tuple.elems.iter().all(|elem| elem == &tuple.elems[0]) {
+                continue;
+            }
+            for field_to in &field.attrs.to.to_items {
+                let conversion = &field_to.params.conversion;
+                if conversion.collection != Some(Collection::Each)
+                    || conversion.pointer == Some(Pointer::Deref)
+                    || conversion.unwrap_newtype
+                {
+                    continue;
+                }
+                for with in &field_to.params.with {
+                    validation.push(
+                        Error::new_spanned(
+                            &with.0,
+                            format!(
+                                "Each with function cannot be applied to tuple {} having components of different types",
+                                tuple.to_token_stream()
+                            ),
+                        ),
+                        &field_to.params.destination,
+                    );
+                }
+            }
+        }
+    }
+    /// Conversion flags of a field can be split between attributes of a destination but shouldn't differ
+    fn validate_conversions(&self, validation: &mut Validation) {
+        for field in &self.fields {
+            let mut conversions: Vec<(&TypePath, &Conversion)> = Vec::new();
+            for field_to in &field.attrs.to.to_items {
+                let (destination, conversion) =
+                    match (&field_to.params.destination, &field_to.params.conversion) {
+                        (Some(destination), conversion) if !conversion.is_empty() => {
+                            (destination, conversion)
+                        }
+                        _ => continue,
+                    };
+                match conversions.iter().find(|(dest, _)| *dest == destination) {
+                    Some((_, current)) if *current != conversion => validation.push(
+                        Error::new_spanned(
+                            field_to.original,
+                            format!(
+                                "Field ({}) has conflicting conversions for destination ({}), specify conversion flags in a single attribute",
+                                field.member.to_token_stream(),
+                                destination.to_token_stream()
+                            ),
+                        ),
+                        Some(destination),
+                    ),
+                    Some(_) => {}
+                    None => conversions.push((destination, conversion)),
                 }
             }
         }
-        Ok(())
     }
 }
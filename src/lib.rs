@@ -38,6 +38,7 @@ struct Person{
 # Disclaimer
 - Macro works only on structs
 - Mapper doesn't handle nested properties
+- Mapper builds on stable Rust, minimum supported version is 1.56
 
 
 # Default behavior
@@ -63,30 +64,315 @@ impl Mapper<Person> for User{
 # Mapping types
 Two mapping types are available :
 - Automatic, generate mapping for destinations specified in [to struct attributes](#to-struct-attribute),
-all fields of the source are used for mapping if they are not explicitly excluded.
-You can't use automatic mapping if additive mapping is already used for destination and strategy
+  all fields of the source are used for mapping if they are not explicitly excluded.
+  You can't use automatic mapping if additive mapping is already used for destination and strategy
 - Additive, generate mapping for destinations specified in [to field attributes](#to-field-attribute), only annotated fields are mapped.
-You can't use additive mapping if automatic mapping is already used for destination and strategy
+  You can't use additive mapping if automatic mapping is already used for destination and strategy
 
 # Mapping strategies
 Two mapping strategies are available :
 - mapper(default), map source to destination without consuming source, generate implementation of [mapper_api::Mapper<T>]
 - into, map source to destination by consuming source  of [std::convert::Into]
 
+# Provided implementations
+[mapper_api::Mapper<T>] is implemented for standard containers as soon as their elements implement it :
+- ```Option<T>```, ```Vec<T>```, ```Box<T>``` and ```Result<T, E>```, as ```Box``` is a fundamental type ```Mapper<Box<U>>``` cannot be implemented for ```Box<T>``` downstream
+- ```HashMap<K, V>``` and ```BTreeMap<K, V>```, keys are cloned and values are mapped
+- tuples up to 8 components
+
+Identity mapping is only implemented for primitive types, ```String``` and ```&str```, so a ```Vec<Option<User>>``` can be mapped into ```Vec<Option<Person>>``` and a ```(User, u8)``` into ```(Person, u8)```.
+A blanket identity implementation would overlap with containers implementations and orphan rules prevent implementing it for types of other crates,
+map such fields with the direct flag or a with function. e.g :
+```ignore
+let users: Vec<Option<User>> = vec![Some(user)];
+let persons: Vec<Option<Person>> = users.to();
+```
+
+# Mapper attribute
+Configure the whole derive, set on the struct.
+- You can set multiple mapper attribute by struct
+
+## Group
+Optional config, name a set of destinations to use it as destination of field attributes.
+Group names are lowercase identifiers, only names declared in mapper attribute are groups and other identifiers of field attributes are destination types.
+A compilation error is raised for empty groups and for lowercase destinations close to a group name or a flag e.g :
+```ignore
+#[derive(Mapper)]
+#[mapper(group(public = [PersonDto, PersonSummary]))]
+#[to(PersonDto, PersonSummary, AdminView)]
+struct User {
+    name: String,
+    #[to(public, exclude)]
+    email: String,
+}
+```
+
+## Global options
+Optional configs, set default options of every to struct attribute : strategies, rename rules and convert or deep flags.
+The ```deny_unmapped``` flag applies to every destination, including destinations of additive mappings.
+Options specified in a to struct attribute override the global options for its destinations, the ```direct``` flag restores cloning or moving fields of a destination when a global convert or deep flag is set e.g :
+```ignore
+#[derive(Mapper)]
+#[mapper(strategy=into, rename_all="camelCase", deep)]
+#[to(PersonDto)]
+#[to(PersonRow, strategy=mapper, rename_all="UPPERCASE")]
+#[to(UserRecord, direct)]
+struct User {
+    first_name: String,
+    home_address: Address,
+}
+```
+
+## Crate
+Generated code refers to mapper traits and to ```Box```, ```Rc``` and ```Arc``` with ```::mapper``` path and to Into trait with ```::core::convert::Into``` path, traits don't need to be in scope of the annotated struct.
+The ```mapper``` crate is required by default, crates depending directly on ```mapper-impl``` and ```mapper-api``` should set ```#[mapper(crate = "mapper_api")]```.
+Optional config, override mapper crate path when it is re-exported by another crate e.g :
+```ignore
+#[derive(Mapper)]
+#[mapper(crate = "facade::mapper")]
+#[to(Person)]
+struct User {
+    name: String,
+}
+```
+Generate 🔄 :
+```ignore
+impl facade::mapper::Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{name: self.name.clone()}
+    }
+}
+```
+
+## Namespaced
+Optional flag, ignore ```#[to(...)]``` attributes on the struct and its fields when ```to``` attribute belongs to another derive.
+Mapping configurations are then only read from ```#[mapper_to(...)]``` and ```#[mapper(to(...))]``` attributes e.g :
+```ignore
+#[derive(Mapper, Table)]
+#[mapper(namespaced, to(Person))]
+#[to(table = "users")]
+struct User {
+    #[to(column = "user_name")]
+    #[mapper_to(Person, field=full_name)]
+    name: String,
+}
+```
+
 # To struct attribute
 Generate automatic mapping for specified strategies.
 - You can set multiple to attribute by struct
+- You can write this attribute ```#[mapper_to(...)]``` or ```#[mapper(to(...))]``` if ```to``` attribute is already used by another derive, see namespaced flag
 - Specify one or multiple destination types in this attribute : ```#[to(Animal, Vehicle)]```
 - Specify one or multiple mapping strategies in this attribute : ```#[to(Animal, strategy=into, strategy=mapper)]```
+- Specify flags after destinations in this attribute : ```#[to(String, transparent)]```, ```#[to(Person, convert)]```, ```#[to(Person, deep)]```, ```#[to(Person, direct)]```, ```#[to(Person, deny_unmapped)]```
+- Specify configurations after destinations in this attribute : ```#[to(Person, rename_all = "camelCase")]```, ```#[to(Person, strip_prefix = "usr_")]```, ```#[to(Person, map(name -> full_name))]```
+
+## Transparent
+Optional flag, map a single field tuple struct (newtype) directly from its inner value e.g :
+```ignore
+#[derive(Mapper)]
+#[to(String, transparent)]
+struct Email(String);
+```
+Generate 🔄 :
+```ignore
+impl Mapper<String> for Email{
+    fn to(&self)->String{
+        self.0.clone()
+    }
+}
+```
+
+## Convert
+Optional flag, convert every field without with function through [mapper_api::FieldConvert<Dst>] trait instead of cloning or moving it.
+Conversions between two types are then implemented once by crate instead of by field.
+Generated code refers to [mapper_api::FieldConvert<Dst>] trait by its full path, the trait only needs to be imported to implement it e.g :
+```ignore
+use mapper::{FieldConvert, Mapper};
+
+impl FieldConvert<String> for AccountId {
+    fn convert(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[derive(Mapper)]
+#[to(Person, convert)]
+struct User {
+    account_id: AccountId,
+    name: String,
+}
+struct Person {
+    account_id: String,
+    name: String,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{account_id: FieldConvert::convert(&self.account_id), name: FieldConvert::convert(&self.name)}
+    }
+}
+```
+
+Fields of the same type are silently cloned by the reflexive implementation ```impl<T: Clone> FieldConvert<T> for T```, even when a with function was intended.
+Orphan rules forbid implementing [mapper_api::FieldConvert<Dst>] between two types of other crates, e.g ```Uuid``` to ```String```, ```DateTime``` or ```Decimal``` conversions.
+Wrap one of both types in a newtype of your crate or map these fields with a with function e.g :
+```ignore
+struct OrderId(Uuid);
+
+impl FieldConvert<String> for OrderId {
+    fn convert(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[derive(Mapper)]
+#[to(OrderDto, convert)]
+struct Order {
+    id: OrderId,
+    #[to(OrderDto, with=format_date)]
+    created_at: DateTime<Utc>,
+}
+```
+
+## Deep
+Optional flag, map every field without with function through [mapper_api::Mapper<T>] trait with mapper strategy or through Into trait with into strategy instead of cloning or moving it.
+Nested structs and containers are then mapped without annotating each field.
+With into strategy every type converts into itself, with mapper strategy a field of the same type in source and destination is only mapped if its type implements [mapper_api::Mapper<T>] to itself,
+identity implementations are provided for primitive types (```bool```, ```char```, integers, floats and ```()```), ```String``` and ```&str``` and for containers of these types.
+Fields of other types, like ```Uuid``` or ```DateTime```, don't compile with mapper strategy unless they are marked with the direct flag or a with function.
+Convert and deep flags cannot be combined e.g :
+```ignore
+#[derive(Mapper)]
+#[to(PersonDto, deep)]
+struct User {
+    name: String,
+    address: Address,
+    previous_addresses: Vec<Address>,
+}
+struct PersonDto {
+    name: String,
+    address: AddressDto,
+    previous_addresses: Vec<AddressDto>,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<PersonDto> for User{
+    fn to(&self)->PersonDto{
+        PersonDto{name: Mapper::to(&self.name), address: Mapper::to(&self.address), previous_addresses: Mapper::to(&self.previous_addresses)}
+    }
+}
+```
+
+## Rename all
+Optional config, rename destination fields of automatic mapping from source fields names with a case convention : ```lowercase```, ```UPPERCASE```, ```PascalCase```, ```camelCase```, ```snake_case``` or ```SCREAMING_SNAKE_CASE```.
+Destination field specified with field attribute config is kept as is e.g :
+```ignore
+#[derive(Mapper)]
+#[to(PersonDto, rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    #[to(PersonDto, field=name)]
+    first_name: String,
+}
+struct PersonDto {
+    userId: u32,
+    name: String,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<PersonDto> for User{
+    fn to(&self)->PersonDto{
+        PersonDto{userId: self.user_id.clone(), name: self.first_name.clone()}
+    }
+}
+```
+
+## Prefix and suffix
+Optional configs, compute destination fields of automatic mapping by stripping or adding prefixes and suffixes to source fields names : ```strip_prefix```, ```strip_suffix```, ```add_prefix``` and ```add_suffix```.
+Rules are applied in this order : strip, rename all case conversion, add. A compilation error is raised if a rule cannot be applied to a field, fields excluded or with a field attribute config for the destination are ignored e.g :
+```ignore
+#[derive(Mapper)]
+#[to(Person, strip_prefix = "usr_")]
+struct UserRow {
+    usr_name: String,
+    #[to(Person, field=email)]
+    mail: String,
+}
+struct Person {
+    name: String,
+    email: String,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for UserRow{
+    fn to(&self)->Person{
+        Person{name: self.usr_name.clone(), email: self.mail.clone()}
+    }
+}
+```
+
+## Mapping table
+Optional config, map source fields from the struct attribute when field attributes cannot or shouldn't be added.
+Each entry targets a destination field ```source -> field```, optionally with a function ```source -> field with func```, or excludes the source field ```source -> _```.
+Like the field attribute with config, the function is used for the mapper strategy, use ```source -> field with(into) func``` to use it for the into strategy.
+Entries are equivalent to field attribute field, with and exclude params for every destination of the attribute, a field cannot be specified in mapping table and in field attribute for the same destination e.g :
+```ignore
+#[derive(Mapper)]
+#[to(Person, map(name -> full_name, age -> years with years, password -> _))]
+struct User {
+    name: String,
+    age: u8,
+    password: String,
+    email: String,
+}
+struct Person {
+    full_name: String,
+    years: u16,
+    email: String,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{full_name: self.name.clone(), years: years(&self.age), email: self.email.clone()}
+    }
+}
+```
+
+## Deny unmapped
+Optional flag, every source field should be mapped or explicitly excluded for destinations of the attribute, for every strategy of the destination including additive mappings.
+Set it in mapper attribute ```#[mapper(deny_unmapped)]``` to apply it to every destination, a field added to the source struct and forgotten in an additive mapping is then reported at compile time e.g :
+```ignore
+#[derive(Mapper)]
+#[to(Person, strategy=into, deny_unmapped)]
+struct User {
+    #[to(Person, strategy=mapper)]
+    name: String,
+    #[to(exclude)]
+    password: String,
+    // error: Field (email) is neither mapped nor excluded for destination (Person) and strategy (mapper)
+    email: String,
+}
+```
 
 # To field attribute
 Complete automatic mapping configuration set on parent struct or provide additive mapping or exclude field from any mappings
 - You can set multiple to attribute by field
+- You can write this attribute ```#[mapper_to(...)]``` or ```#[mapper(to(...))]``` if ```to``` attribute is already used by another derive, see namespaced flag
 - This attribute is forbidden if you use only DestinationType
 
 
 ## DestinationType
 Type of the mapping destination. Mandatory argument unless field is unconditionally excluded.
+You can specify multiple destinations sharing the same configuration : ```#[to(PersonDto, PersonSummary, with=fmt_name)]```
+Destination groups declared in mapper attribute can be used as destinations : ```#[to(public, exclude)]```
 ### Generics
 You can specify destination type with generics, these generics should be compatible with the fields of your src struct :
 ```ignore
@@ -102,6 +388,22 @@ struct Person<T, U> {
 }
 ```
 
+### Wildcard
+Use ```*``` as destination to apply the configuration to every destination declared on the parent struct.
+A field attribute with a specific destination overrides the wildcard for this destination, with functions are only applied to destinations using their strategy and wildcard cannot specify strategies.
+A compilation error is raised if the struct declares no destination or if no with function matches a strategy of a destination, e.g ```with``` defaults to mapper strategy and requires ```with(into)``` for destinations only mapped with into strategy e.g :
+```ignore
+#[derive(Mapper)]
+#[to(PersonDto, AdminView)]
+struct User {
+    #[to(*, exclude)]
+    password_hash: String,
+    #[to(*, field=created, with=fmt_date)]
+    #[to(AdminView, field=created_at)]
+    created_at: u64,
+}
+```
+
 ## Strategy
 Trigger additive mapping for mapping destination and specified strategy e.g:
 
@@ -122,7 +424,7 @@ impl Into<Person> for User{
 
 
 ## Exclude
-Optional parameter, specify if the field is excluded for mapping, there is 2 kind of exclusion.
+Optional parameter, specify if the field is excluded for mapping, there is 3 kind of exclusion.
 - Unconditionally exclusion, exclude field of any kind of mapping e.g :
 
 ````ignore
@@ -141,7 +443,7 @@ impl Mapper<Person> for User{
 }
 ```
 
-- Exclusion for specific destination (⚠️ not works for additive mapping), exclude field for specific destination mapping e.g :
+- Exclusion for specific destination, exclude field for specific destination mapping, automatic or additive e.g :
 ````ignore
 #[derive(Mapper)]
 #[to(Person,Account, strategy=into)]
@@ -164,9 +466,25 @@ impl Into<Account> for User{
 }
 ```
 
+- Exclusion for specific destination and strategy, exclude field only for listed strategies of the destination : ```#[to(Person, exclude(strategy=into))]```.
+  The destination should be mapped with excluded strategies and the field cannot be mapped and excluded for the same destination and strategy.
+  Exclusion of a field for an additive mapping is useful with [deny unmapped](#deny-unmapped) flag to acknowledge that the field isn't mapped e.g :
+```ignore
+#[derive(Mapper)]
+#[mapper(deny_unmapped)]
+#[to(Account, strategy=into)]
+struct User {
+    #[to(Person, strategy=mapper)]
+    name: String,
+    #[to(Person, exclude)]
+    #[to(Account, exclude(strategy=into))]
+    password: String,
+}
+```
+
 
 ## Field
-Optional parameter, target the destination type field e.g :
+Optional parameter, target the destination type field with an identifier, a tuple index or a string literal containing an identifier, keywords are escaped as raw identifiers : ```field=name```, ```field=0```, ```field="type"``` e.g :
 ````ignore
 #[derive(Mapper)]
 #[to(Person)]
@@ -216,6 +534,135 @@ You can use generics in your function if the generic types constraint respect th
     }
 ```
 
+## Direct
+Optional flag, clone the annotated field with mapper strategy or move it with into strategy whatever the convert or deep flag of its destination.
+Use it for fields whose type has no [mapper_api::Mapper<T>] implementation to itself, e.g types of other crates in deep mode :
+```ignore
+#[derive(Mapper)]
+#[to(Server, deep)]
+struct Host {
+    name: String,
+    #[to(Server, direct)]
+    ip: Ipv4Addr,
+}
+```
+
+## Pointer
+Optional flag, wrap the destination field into a smart pointer or unwrap the source field from it :
+- boxed, rc, arc : wrap the mapped value with ```Box::new```, ```Rc::new``` or ```Arc::new```
+- deref : clone the value out of the source pointer, if a with function is provided it receives the dereferenced value
+
+Only one pointer flag can be specified by attribute. A pointer flag can be set in another attribute of the destination than its field or with configurations, attributes of a destination specifying different conversions are rejected.
+Pointer flags compose with with functions, e.g ```Box<Child>``` to ```ChildDto``` using [mapper_api::Mapper<T>] :
+```ignore
+#[derive(Mapper)]
+#[to(ParentDto)]
+struct Parent {
+    #[to(ParentDto, deref, with=Mapper::to)]
+    child: Box<Child>,
+}
+struct ParentDto {
+    child: ChildDto,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<ParentDto> for Parent{
+    fn to(&self)->ParentDto{
+        ParentDto{child: Mapper::to(&*self.child)}
+    }
+}
+```
+
+## Newtype
+Optional parameters, remove or add a newtype layer (single field tuple struct) around the mapped value :
+- unwrap_newtype : map the inner value of the source field instead of the field itself
+- wrap_newtype=NewType : wrap the mapped value into the specified newtype
+
+Both parameters can be combined to convert a newtype into another one e.g :
+```ignore
+struct Email(String);
+struct Login(String);
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, unwrap_newtype)]
+    email: Email,
+    #[to(Person, unwrap_newtype, wrap_newtype=Login)]
+    login: Email,
+}
+struct Person {
+    email: String,
+    login: Login,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{email: self.email.0.clone(), login: Login(self.login.0.clone())}
+    }
+}
+```
+
+## Collection
+Optional parameters, convert a collection field into another kind of collection :
+- collect_by_key=element_field : collect a sequence into a map keyed by the specified field of each element, the key should implement [std::clone::Clone]
+- values : collect the values of a map into a sequence
+- each : map a fixed size array element by element, a tuple component by component or any other iterable (slice, vector...) into a collected sequence, ```each(with=foo_mapping)``` is a shorthand for ```each, with=foo_mapping```, a with function can only be applied to tuples whose components have the same type
+
+When a collection parameter is specified, with function is applied to each element instead of the whole field e.g :
+```ignore
+fn map_item(item: &Item) -> ItemDto{
+    ItemDto{ name: item.name.clone() }
+}
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, collect_by_key=id, with=map_item)]
+    items: Vec<Item>,
+}
+struct Person {
+    items: HashMap<u16, ItemDto>,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{items: self.items.iter().map(|item| (item.id.clone(), map_item(&(*item)))).collect()}
+    }
+}
+```
+
+# Evaluation order
+Generated code is the same between compilations : implementations are generated in attribute order of destinations and strategies, and destination fields are initialized in declaration order of source fields.
+Initializers, including `with` functions, are evaluated in this order :
+```ignore
+#[derive(Mapper)]
+#[to(Person)]
+struct User{
+    #[to(Person, field=id, with=next_id)]
+    name: String,
+    #[to(Person, field=name)]
+    id: u16,
+}
+```
+Generate 🔄 :
+```ignore
+impl Mapper<Person> for User{
+    fn to(&self)->Person{
+        Person{id: next_id(&self.name), name: self.id.clone()}
+    }
+}
+```
+
+# Errors
+All attribute errors of a struct are reported at once. Unknown configurations and flags are listed with the closest known name, lowercase destinations are only rejected when they are close to a flag name. Implementations of destinations without errors are still generated, so the rest of the code keeps compiling against them. An invalid attribute only invalidates the destinations it targets, mapper attribute errors and attributes whose destinations cannot be parsed prevent every implementation of the struct.
+Type errors of generated code are reported on the source field, or on the whole `with = ...` configuration.
+
 */
 
 pub use mapper_api::*;
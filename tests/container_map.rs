@@ -0,0 +1,61 @@
+use std::collections::{BTreeMap, HashMap};
+
+use mapper_api::Mapper;
+use mapper_impl::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: String,
+}
+
+fn user(name: &str) -> User {
+    User {
+        name: name.to_owned(),
+    }
+}
+
+fn person(name: &str) -> Person {
+    Person {
+        name: name.to_owned(),
+    }
+}
+
+#[test]
+pub fn option_vec_and_box_should_map_their_elements() {
+    let users = vec![Some(user("Marie")), None];
+    let persons: Vec<Option<Person>> = users.to();
+    assert_eq!(vec![Some(person("Marie")), None], persons);
+
+    let boxed: Box<Person> = Box::new(user("Marie")).to();
+    assert_eq!(person("Marie"), *boxed);
+}
+
+#[test]
+pub fn maps_should_map_their_values() {
+    let users: HashMap<u16, User> = [(1, user("Marie"))].into_iter().collect();
+    let persons: HashMap<u16, Person> = users.to();
+    assert_eq!(person("Marie"), persons[&1]);
+
+    let users: BTreeMap<String, Vec<User>> = [("admins".to_owned(), vec![user("Paul")])]
+        .into_iter()
+        .collect();
+    let persons: BTreeMap<String, Vec<Person>> = users.to();
+    assert_eq!(vec![person("Paul")], persons["admins"]);
+}
+
+#[test]
+pub fn tuples_and_results_should_map_their_components() {
+    let pair = (user("Marie"), 30_u8);
+    let mapped: (Person, u8) = pair.to();
+    assert_eq!((person("Marie"), 30), mapped);
+
+    let result: Result<User, String> = Ok(user("Marie"));
+    let mapped: Result<Person, String> = result.to();
+    assert_eq!(Ok(person("Marie")), mapped);
+}
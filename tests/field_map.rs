@@ -65,6 +65,7 @@ pub fn map_generic_destination_type_with_func_should_works() {
 pub fn exclude_field_should_works() {
     #[derive(Mapper)]
     #[to(Person)]
+    #[allow(dead_code)]
     struct User {
         age: u16,
         #[to(Person, exclude)]
@@ -139,6 +140,7 @@ pub fn use_with_strategy_should_works_for_for_additive_and_automatic_mapping() {
 pub fn exclude_field_without_destination_should_never_be_mapped() {
     #[derive(Mapper)]
     #[to(Person)]
+    #[allow(dead_code)]
     struct User {
         #[to(exclude)]
         name: String,
@@ -188,3 +190,516 @@ pub fn field_mapping_should_works() {
     let person_mapper: Person = user.to();
     assert_eq!(30, person_mapper._age);
 }
+
+#[test]
+pub fn pointer_flags_should_wrap_field() {
+    use std::{rc::Rc, sync::Arc};
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, rc)]
+        name: String,
+        #[to(Person, boxed)]
+        age: u16,
+        #[to(Person, arc)]
+        account_id: String,
+    }
+    struct Person {
+        name: Rc<String>,
+        age: Box<u16>,
+        account_id: Arc<String>,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 30,
+        account_id: "ID-123".to_owned(),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("Marie", *person_mapper.name);
+    assert_eq!(30, *person_mapper.age);
+    assert_eq!("ID-123", *person_into.account_id);
+}
+
+#[test]
+pub fn deref_flag_should_unwrap_field() {
+    use std::sync::Arc;
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, deref)]
+        name: Arc<String>,
+    }
+    struct Person {
+        name: String,
+    }
+    let user = User {
+        name: Arc::new("Marie".to_owned()),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("Marie", person_mapper.name);
+    assert_eq!("Marie", person_into.name);
+}
+
+#[test]
+pub fn deref_flag_should_compose_with_nested_mapping() {
+    #[derive(Mapper)]
+    #[to(ChildDto)]
+    struct Child {
+        name: String,
+    }
+    struct ChildDto {
+        name: String,
+    }
+    #[derive(Mapper)]
+    #[to(ParentDto)]
+    struct Parent {
+        #[to(ParentDto, deref, with=Mapper::to)]
+        child: Box<Child>,
+    }
+    struct ParentDto {
+        child: ChildDto,
+    }
+    let parent = Parent {
+        child: Box::new(Child {
+            name: "Marie".to_owned(),
+        }),
+    };
+    let parent_dto: ParentDto = parent.to();
+    assert_eq!("Marie", parent_dto.child.name);
+}
+
+#[test]
+pub fn pointer_flag_should_be_kept_with_field_in_another_attribute() {
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, boxed)]
+        #[to(Person, field=years)]
+        age: u8,
+    }
+    struct Person {
+        years: Box<u8>,
+    }
+    let user = User { age: 30 };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!(30, *person_mapper.years);
+    assert_eq!(30, *person_into.years);
+}
+
+#[test]
+pub fn newtype_flags_should_unwrap_and_wrap_field() {
+    struct Email(String);
+    struct Login(String);
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, unwrap_newtype)]
+        email: Email,
+        #[to(Person, wrap_newtype=Email)]
+        backup_email: String,
+        #[to(Person, unwrap_newtype, wrap_newtype=Login)]
+        login: Email,
+    }
+    struct Person {
+        email: String,
+        backup_email: Email,
+        login: Login,
+    }
+    let user = User {
+        email: Email("marie@mail.com".to_owned()),
+        backup_email: "marie@backup.com".to_owned(),
+        login: Email("marie".to_owned()),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("marie@mail.com", person_mapper.email);
+    assert_eq!("marie@backup.com", person_mapper.backup_email.0);
+    assert_eq!("marie", person_into.login.0);
+}
+
+#[test]
+pub fn newtype_flags_should_be_kept_with_field_in_another_attribute() {
+    struct Email(String);
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, unwrap_newtype)]
+        #[to(Person, field=address)]
+        email: Email,
+        #[to(Person, wrap_newtype=Email)]
+        #[to(Person, field=backup_address)]
+        backup_email: String,
+    }
+    struct Person {
+        address: String,
+        backup_address: Email,
+    }
+    let user = User {
+        email: Email("marie@mail.com".to_owned()),
+        backup_email: "marie@backup.com".to_owned(),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("marie@mail.com", person_mapper.address);
+    assert_eq!("marie@backup.com", person_into.backup_address.0);
+}
+
+#[test]
+pub fn collect_by_key_should_index_elements() {
+    use std::collections::HashMap;
+    #[derive(Clone)]
+    struct Item {
+        id: u16,
+        name: String,
+    }
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, collect_by_key=id)]
+        items: Vec<Item>,
+    }
+    struct Person {
+        items: HashMap<u16, Item>,
+    }
+    let user = User {
+        items: vec![Item {
+            id: 1,
+            name: "Book".to_owned(),
+        }],
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("Book", person_mapper.items[&1].name);
+    assert_eq!("Book", person_into.items[&1].name);
+}
+
+#[test]
+pub fn values_should_map_each_element_with_func() {
+    use std::collections::BTreeMap;
+    fn map_mapper(val: &u16) -> String {
+        val.to_string()
+    }
+    fn map_into(val: u16) -> String {
+        val.to_string()
+    }
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, values, with(mapper)=map_mapper, with(into)=map_into)]
+        scores: BTreeMap<String, u16>,
+    }
+    struct Person {
+        scores: Vec<String>,
+    }
+    let user = User {
+        scores: [("math".to_owned(), 12), ("art".to_owned(), 15)]
+            .into_iter()
+            .collect(),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!(vec!["15", "12"], person_mapper.scores);
+    assert_eq!(vec!["15", "12"], person_into.scores);
+}
+
+#[test]
+pub fn each_should_map_array_and_tuple_elements() {
+    fn to_f64(val: &f32) -> f64 {
+        *val as f64
+    }
+    fn to_u16(val: &u8) -> u16 {
+        *val as u16
+    }
+    #[derive(Mapper)]
+    #[to(Shape, strategy=all)]
+    struct Point {
+        #[to(Shape, each(with(mapper)=to_f64), each(with(into)=f64::from))]
+        position: [f32; 3],
+        #[to(Shape, each(with(mapper)=to_u16), each(with(into)=u16::from))]
+        color: (u8, u8, u8),
+    }
+    struct Shape {
+        position: [f64; 3],
+        color: (u16, u16, u16),
+    }
+    let point = Point {
+        position: [1.0, 2.0, 3.0],
+        color: (255, 0, 10),
+    };
+    let shape_mapper: Shape = point.to();
+    let shape_into: Shape = point.into();
+    assert_eq!([1.0, 2.0, 3.0], shape_mapper.position);
+    assert_eq!((255, 0, 10), shape_mapper.color);
+    assert_eq!([1.0, 2.0, 3.0], shape_into.position);
+    assert_eq!((255, 0, 10), shape_into.color);
+}
+
+#[test]
+pub fn each_should_map_arrays_of_constant_length_without_clone() {
+    const LEN: usize = 2;
+    struct Tag(u8);
+    fn to_label(tag: &Tag) -> String {
+        tag.0.to_string()
+    }
+    #[derive(Mapper)]
+    #[to(Label)]
+    struct Item {
+        #[to(Label, each(with=to_label))]
+        tags: [Tag; LEN],
+    }
+    struct Label {
+        tags: [String; LEN],
+    }
+    let item = Item {
+        tags: [Tag(1), Tag(2)],
+    };
+    let label: Label = item.to();
+    assert_eq!(["1", "2"], label.tags);
+}
+
+#[test]
+pub fn each_should_collect_slice_into_vec() {
+    #[derive(Mapper)]
+    #[to(Person, strategy=all)]
+    struct User {
+        #[to(Person, each)]
+        names: &'static [&'static str],
+    }
+    struct Person {
+        names: Vec<&'static str>,
+    }
+    let user = User {
+        names: &["Marie", "Paul"],
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!(vec!["Marie", "Paul"], person_mapper.names);
+    assert_eq!(vec!["Marie", "Paul"], person_into.names);
+}
+
+#[test]
+pub fn field_should_accept_string_literals_and_tuple_indices() {
+    #[derive(Mapper)]
+    #[to(Item)]
+    #[to(Pair)]
+    struct Product {
+        #[to(Item, field = "type")]
+        #[to(Pair, field = 0)]
+        kind: String,
+        #[to(Item, field = r#ref)]
+        #[to(Pair, field = 1)]
+        reference: u32,
+    }
+    struct Item {
+        r#type: String,
+        r#ref: u32,
+    }
+    struct Pair(String, u32);
+    let product = Product {
+        kind: "book".to_owned(),
+        reference: 42,
+    };
+    let item: Item = product.to();
+    let pair: Pair = product.to();
+    assert_eq!("book", item.r#type);
+    assert_eq!(42, item.r#ref);
+    assert_eq!("book", pair.0);
+    assert_eq!(42, pair.1);
+}
+
+#[test]
+pub fn wildcard_should_apply_to_all_destinations_unless_overridden() {
+    fn fmt_date(timestamp: &u64) -> String {
+        format!("{}s", timestamp)
+    }
+    #[derive(Mapper)]
+    #[to(PersonDto, PersonSummary, AdminView)]
+    #[allow(dead_code)]
+    struct User {
+        #[to(*, exclude)]
+        password_hash: String,
+        #[to(*, field=created, with=fmt_date)]
+        #[to(AdminView, field=created_at)]
+        created_at: u64,
+    }
+    struct PersonDto {
+        created: String,
+    }
+    struct PersonSummary {
+        created: String,
+    }
+    struct AdminView {
+        created_at: u64,
+    }
+    let user = User {
+        password_hash: "hash".to_owned(),
+        created_at: 42,
+    };
+    let dto: PersonDto = user.to();
+    let summary: PersonSummary = user.to();
+    let admin: AdminView = user.to();
+    assert_eq!("42s", dto.created);
+    assert_eq!("42s", summary.created);
+    assert_eq!(42, admin.created_at);
+}
+
+#[test]
+pub fn field_attribute_should_target_multiple_destinations() {
+    fn fmt_name(name: &str) -> String {
+        name.to_uppercase()
+    }
+    #[derive(Mapper)]
+    #[to(PersonDto, PersonSummary, AdminView)]
+    #[allow(dead_code)]
+    struct User {
+        #[to(PersonDto, PersonSummary, with=fmt_name)]
+        name: String,
+        #[to(PersonDto, PersonSummary, exclude)]
+        email: String,
+    }
+    struct PersonDto {
+        name: String,
+    }
+    struct PersonSummary {
+        name: String,
+    }
+    struct AdminView {
+        name: String,
+        email: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        email: "marie@mail.com".to_owned(),
+    };
+    let dto: PersonDto = user.to();
+    let summary: PersonSummary = user.to();
+    let admin: AdminView = user.to();
+    assert_eq!("MARIE", dto.name);
+    assert_eq!("MARIE", summary.name);
+    assert_eq!("Marie", admin.name);
+    assert_eq!("marie@mail.com", admin.email);
+}
+
+#[test]
+pub fn destination_groups_should_expand_to_their_destinations() {
+    #[derive(Mapper)]
+    #[mapper(group(public = [PersonDto, PersonSummary]))]
+    #[to(PersonDto, PersonSummary, AdminView)]
+    #[allow(dead_code)]
+    struct User {
+        name: String,
+        #[to(public, exclude)]
+        email: String,
+    }
+    struct PersonDto {
+        name: String,
+    }
+    struct PersonSummary {
+        name: String,
+    }
+    struct AdminView {
+        name: String,
+        email: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        email: "marie@mail.com".to_owned(),
+    };
+    let dto: PersonDto = user.to();
+    let summary: PersonSummary = user.to();
+    let admin: AdminView = user.to();
+    assert_eq!("Marie", dto.name);
+    assert_eq!("Marie", summary.name);
+    assert_eq!("Marie", admin.name);
+    assert_eq!("marie@mail.com", admin.email);
+}
+
+#[test]
+#[allow(non_camel_case_types)]
+pub fn lowercase_destination_should_not_be_resolved_as_group() {
+    #[derive(Mapper)]
+    #[mapper(group(public = [PersonDto]))]
+    #[to(PersonDto)]
+    struct User {
+        #[to(public, field=full_name)]
+        #[to(person_row, strategy=mapper, field=label)]
+        name: String,
+    }
+    struct PersonDto {
+        full_name: String,
+    }
+    struct person_row {
+        label: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+    };
+    let dto: PersonDto = user.to();
+    let row: person_row = user.to();
+    assert_eq!("Marie", dto.full_name);
+    assert_eq!("Marie", row.label);
+}
+
+#[test]
+pub fn deny_unmapped_should_accept_mapped_or_excluded_fields() {
+    #[derive(Mapper)]
+    #[to(Person, strategy=into, deny_unmapped)]
+    #[allow(dead_code)]
+    struct User {
+        #[to(Person, strategy=mapper)]
+        name: String,
+        #[to(Person, strategy=mapper, field=years)]
+        age: u8,
+        #[to(exclude)]
+        password: String,
+    }
+    struct Person {
+        name: String,
+        years: u8,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+        password: "secret".to_owned(),
+    };
+    let person: Person = user.to();
+    assert_eq!("Marie", person.name);
+    assert_eq!(32, person.years);
+}
+
+#[test]
+pub fn exclude_should_apply_to_additive_mappings_and_strategies() {
+    #[derive(Mapper)]
+    #[mapper(deny_unmapped)]
+    #[to(Account, strategy=into)]
+    #[allow(dead_code)]
+    struct User {
+        #[to(Person, strategy=mapper)]
+        name: String,
+        #[to(Person, exclude)]
+        email: String,
+        #[to(Person, exclude(strategy=mapper))]
+        #[to(Account, exclude(strategy=into))]
+        password: String,
+    }
+    struct Person {
+        name: String,
+    }
+    struct Account {
+        name: String,
+        email: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        email: "marie@mail.com".to_owned(),
+        password: "secret".to_owned(),
+    };
+    let person: Person = user.to();
+    assert_eq!("Marie", person.name);
+    let account: Account = user.into();
+    assert_eq!("Marie", account.name);
+    assert_eq!("marie@mail.com", account.email);
+}
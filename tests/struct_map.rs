@@ -64,6 +64,7 @@ pub fn map_field_with_generics_destination_type() {
         name: String,
         age: u8,
     }
+    #[allow(dead_code)]
     struct Person<T, U> {
         name: T,
         age: U,
@@ -124,3 +125,525 @@ pub fn mapping_works_for_tuple_structs() {
     assert_eq!(30, person_into.0);
     assert_eq!(30, person_mapper.0);
 }
+
+#[test]
+pub fn transparent_mapping_should_map_inner_value() {
+    #[derive(Mapper)]
+    #[to(String, strategy=all, transparent)]
+    struct Email(String);
+    let email = Email("marie@mail.com".to_owned());
+    let mapped: String = email.to();
+    let consumed: String = email.into();
+    assert_eq!("marie@mail.com", mapped);
+    assert_eq!("marie@mail.com", consumed);
+}
+
+#[test]
+pub fn convert_mode_should_map_fields_through_field_convert() {
+    use mapper_api::FieldConvert;
+    struct AccountId(u32);
+    impl FieldConvert<String> for AccountId {
+        fn convert(&self) -> String {
+            format!("ID-{}", self.0)
+        }
+    }
+    #[derive(Mapper)]
+    #[to(Person, strategy=all, convert)]
+    struct User {
+        account_id: AccountId,
+        name: String,
+    }
+    struct Person {
+        account_id: String,
+        name: String,
+    }
+    let user = User {
+        account_id: AccountId(123),
+        name: "Marie".to_owned(),
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("ID-123", person_mapper.account_id);
+    assert_eq!("Marie", person_mapper.name);
+    assert_eq!("ID-123", person_into.account_id);
+}
+
+#[test]
+pub fn deep_mode_should_map_nested_fields() {
+    #[derive(Mapper)]
+    #[to(AddressDto, strategy=all)]
+    struct Address {
+        city: String,
+    }
+    struct AddressDto {
+        city: String,
+    }
+    #[derive(Mapper)]
+    #[to(PersonDto, deep)]
+    struct User {
+        name: String,
+        age: u8,
+        address: Address,
+        previous_addresses: Vec<Option<Address>>,
+    }
+    struct PersonDto {
+        name: String,
+        age: u8,
+        address: AddressDto,
+        previous_addresses: Vec<Option<AddressDto>>,
+    }
+    #[derive(Mapper)]
+    #[to(ContactDto, strategy=into, deep)]
+    struct Contact {
+        name: String,
+        address: Address,
+    }
+    struct ContactDto {
+        name: String,
+        address: AddressDto,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+        address: Address {
+            city: "Paris".to_owned(),
+        },
+        previous_addresses: vec![
+            Some(Address {
+                city: "Lyon".to_owned(),
+            }),
+            None,
+        ],
+    };
+    let person: PersonDto = user.to();
+    assert_eq!("Marie", person.name);
+    assert_eq!(32, person.age);
+    assert_eq!("Paris", person.address.city);
+    assert_eq!("Lyon", person.previous_addresses[0].as_ref().unwrap().city);
+    assert!(person.previous_addresses[1].is_none());
+    let contact = Contact {
+        name: "Marie".to_owned(),
+        address: Address {
+            city: "Paris".to_owned(),
+        },
+    };
+    let contact: ContactDto = contact.into();
+    assert_eq!("Marie", contact.name);
+    assert_eq!("Paris", contact.address.city);
+}
+
+#[test]
+pub fn direct_field_flag_should_bypass_deep_mode() {
+    use std::net::Ipv4Addr;
+    #[derive(Clone)]
+    struct Tag(String);
+    #[derive(Mapper)]
+    #[to(Server, deep)]
+    struct Host {
+        name: String,
+        #[to(Server, direct)]
+        ip: Ipv4Addr,
+        #[to(Server, direct)]
+        tags: Vec<Tag>,
+    }
+    struct Server {
+        name: String,
+        ip: Ipv4Addr,
+        tags: Vec<Tag>,
+    }
+    let host = Host {
+        name: "api".to_owned(),
+        ip: Ipv4Addr::LOCALHOST,
+        tags: vec![Tag("prod".to_owned())],
+    };
+    let server: Server = host.to();
+    assert_eq!("api", server.name);
+    assert_eq!(Ipv4Addr::LOCALHOST, server.ip);
+    assert_eq!("prod", server.tags[0].0);
+}
+
+#[test]
+#[allow(non_snake_case)]
+pub fn rename_all_should_rename_automatic_fields() {
+    #[derive(Mapper)]
+    #[to(PersonDto, rename_all = "camelCase")]
+    #[to(PersonRow, rename_all = "SCREAMING_SNAKE_CASE")]
+    struct User {
+        user_id: u32,
+        #[to(PersonDto, field=name)]
+        first_name: String,
+    }
+    struct PersonDto {
+        userId: u32,
+        name: String,
+    }
+    struct PersonRow {
+        USER_ID: u32,
+        FIRST_NAME: String,
+    }
+    let user = User {
+        user_id: 123,
+        first_name: "Marie".to_owned(),
+    };
+    let dto: PersonDto = user.to();
+    let row: PersonRow = user.to();
+    assert_eq!(123, dto.userId);
+    assert_eq!("Marie", dto.name);
+    assert_eq!(123, row.USER_ID);
+    assert_eq!("Marie", row.FIRST_NAME);
+}
+
+#[test]
+pub fn prefix_and_suffix_rules_should_rename_automatic_fields() {
+    #[derive(Mapper)]
+    #[to(Person, strip_prefix = "usr_", strip_suffix = "_col")]
+    #[to(PersonDto, rename_all = "PascalCase", add_prefix = "dto_")]
+    struct UserRow {
+        usr_name_col: String,
+        #[to(Person, field=mail)]
+        usr_email: String,
+    }
+    struct Person {
+        name: String,
+        mail: String,
+    }
+    #[allow(non_snake_case)]
+    struct PersonDto {
+        dto_UsrNameCol: String,
+        dto_UsrEmail: String,
+    }
+    let user = UserRow {
+        usr_name_col: "Marie".to_owned(),
+        usr_email: "marie@mail.com".to_owned(),
+    };
+    let person: Person = user.to();
+    let dto: PersonDto = user.to();
+    assert_eq!("Marie", person.name);
+    assert_eq!("marie@mail.com", person.mail);
+    assert_eq!("Marie", dto.dto_UsrNameCol);
+    assert_eq!("marie@mail.com", dto.dto_UsrEmail);
+}
+
+#[test]
+pub fn mapping_table_should_rename_transform_and_exclude_fields() {
+    fn years(age: &u8) -> u16 {
+        *age as u16
+    }
+    #[derive(Mapper)]
+    #[to(Person, map(name -> full_name, age -> years with years, password -> _))]
+    #[allow(dead_code)]
+    struct User {
+        name: String,
+        age: u8,
+        password: String,
+        email: String,
+    }
+    struct Person {
+        full_name: String,
+        years: u16,
+        email: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+        password: "secret".to_owned(),
+        email: "marie@mail.com".to_owned(),
+    };
+    let person: Person = user.to();
+    assert_eq!("Marie", person.full_name);
+    assert_eq!(32, person.years);
+    assert_eq!("marie@mail.com", person.email);
+}
+
+#[test]
+pub fn mapping_table_with_should_apply_to_its_strategy() {
+    fn next_year(age: &u8) -> u8 {
+        age + 1
+    }
+    fn shout(name: String) -> String {
+        name.to_uppercase()
+    }
+    #[derive(Mapper)]
+    #[to(Person, strategy=all, map(age -> years with next_year, name -> full_name with(into) shout))]
+    struct User {
+        name: String,
+        age: u8,
+    }
+    struct Person {
+        full_name: String,
+        years: u8,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+    };
+    let person_mapper: Person = user.to();
+    let person_into: Person = user.into();
+    assert_eq!("Marie", person_mapper.full_name);
+    assert_eq!(33, person_mapper.years);
+    assert_eq!("MARIE", person_into.full_name);
+    assert_eq!(32, person_into.years);
+}
+
+#[test]
+#[allow(non_snake_case)]
+pub fn mapper_attribute_should_set_defaults_overridden_by_to_attribute() {
+    #[derive(Mapper)]
+    #[to(AddressDto, strategy=all)]
+    struct Address {
+        city: String,
+    }
+    struct AddressDto {
+        city: String,
+    }
+    #[derive(Mapper)]
+    #[mapper(strategy=into, rename_all="camelCase", deep)]
+    #[to(PersonDto)]
+    #[to(PersonRow, strategy=mapper, rename_all="UPPERCASE")]
+    struct User {
+        first_name: String,
+        home_address: Address,
+    }
+    struct PersonDto {
+        firstName: String,
+        homeAddress: AddressDto,
+    }
+    struct PersonRow {
+        FIRSTNAME: String,
+        HOMEADDRESS: AddressDto,
+    }
+    let user = User {
+        first_name: "Marie".to_owned(),
+        home_address: Address {
+            city: "Paris".to_owned(),
+        },
+    };
+    let row: PersonRow = user.to();
+    assert_eq!("Marie", row.FIRSTNAME);
+    assert_eq!("Paris", row.HOMEADDRESS.city);
+    let dto: PersonDto = user.into();
+    assert_eq!("Marie", dto.firstName);
+    assert_eq!("Paris", dto.homeAddress.city);
+}
+
+#[test]
+pub fn direct_flag_should_override_container_mode() {
+    #[derive(Mapper)]
+    #[to(AddressDto)]
+    #[derive(Clone)]
+    struct Address {
+        city: String,
+    }
+    struct AddressDto {
+        city: String,
+    }
+    #[derive(Mapper)]
+    #[mapper(deep)]
+    #[to(PersonDto)]
+    #[to(PersonRow, direct)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+    struct PersonDto {
+        name: String,
+        address: AddressDto,
+    }
+    struct PersonRow {
+        name: String,
+        address: Address,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        address: Address {
+            city: "Paris".to_owned(),
+        },
+    };
+    let dto: PersonDto = user.to();
+    assert_eq!("Marie", dto.name);
+    assert_eq!("Paris", dto.address.city);
+    let row: PersonRow = user.to();
+    assert_eq!("Marie", row.name);
+    assert_eq!("Paris", row.address.city);
+}
+
+#[test]
+#[allow(non_camel_case_types)]
+pub fn lowercase_destination_should_not_be_rejected_as_flag() {
+    #[derive(Mapper)]
+    #[to(person_row, deep)]
+    struct User {
+        name: String,
+    }
+    struct person_row {
+        name: String,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+    };
+    let row: person_row = user.to();
+    assert_eq!("Marie", row.name);
+}
+
+mod facade {
+    pub use ::mapper as internal_mapper;
+}
+
+mod hygiene {
+    use mapper_impl::Mapper;
+
+    #[allow(dead_code)]
+    trait Into {}
+    #[allow(dead_code)]
+    trait FieldConvert {}
+    #[allow(dead_code)]
+    struct Box;
+
+    #[derive(Mapper)]
+    #[to(Person, strategy=all, deep)]
+    pub struct User {
+        pub name: String,
+    }
+    #[derive(Mapper)]
+    #[mapper(crate = "crate::facade::internal_mapper")]
+    #[to(Person, convert)]
+    pub struct Admin {
+        pub name: String,
+    }
+    #[derive(Mapper)]
+    #[mapper(crate = "mapper_api")]
+    #[to(Profile, strategy=all)]
+    pub struct Guest {
+        #[to(Profile, boxed)]
+        pub age: u8,
+    }
+    pub struct Person {
+        pub name: String,
+    }
+    pub struct Profile {
+        pub age: std::boxed::Box<u8>,
+    }
+}
+
+#[test]
+pub fn generated_code_should_use_qualified_paths() {
+    let user = hygiene::User {
+        name: "Marie".to_owned(),
+    };
+    let person: hygiene::Person = mapper::Mapper::to(&user);
+    assert_eq!("Marie", person.name);
+    let person: hygiene::Person = user.into();
+    assert_eq!("Marie", person.name);
+    let admin = hygiene::Admin {
+        name: "Alice".to_owned(),
+    };
+    let person: hygiene::Person = admin.to();
+    assert_eq!("Alice", person.name);
+    let guest = hygiene::Guest { age: 32 };
+    let profile: hygiene::Profile = mapper_api::Mapper::to(&guest);
+    assert_eq!(32, *profile.age);
+    let profile: hygiene::Profile = guest.into();
+    assert_eq!(32, *profile.age);
+}
+
+#[test]
+pub fn namespaced_attributes_should_configure_mapping() {
+    #[derive(Mapper)]
+    #[mapper(strategy=all, to(Person))]
+    #[mapper_to(PersonDto)]
+    #[allow(dead_code)]
+    struct User {
+        #[mapper(to(Person, field=full_name))]
+        #[mapper_to(PersonDto, exclude)]
+        name: String,
+        age: u8,
+    }
+    struct Person {
+        full_name: String,
+        age: u8,
+    }
+    struct PersonDto {
+        age: u8,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+    };
+    let person: Person = user.to();
+    let dto: PersonDto = user.to();
+    assert_eq!("Marie", person.full_name);
+    assert_eq!(32, person.age);
+    assert_eq!(32, dto.age);
+    let person: Person = user.into();
+    assert_eq!("Marie", person.full_name);
+}
+
+#[test]
+pub fn namespaced_flag_should_ignore_foreign_to_attributes() {
+    #[derive(Mapper)]
+    #[mapper(namespaced, to(Person))]
+    #[to(table = "users", version = 2)]
+    #[allow(dead_code)]
+    struct User {
+        #[to(column = "user_name")]
+        #[mapper_to(Person, field=full_name)]
+        name: String,
+        #[to(skip)]
+        age: u8,
+    }
+    struct Person {
+        full_name: String,
+        age: u8,
+    }
+    let user = User {
+        name: "Marie".to_owned(),
+        age: 32,
+    };
+    let person: Person = user.to();
+    assert_eq!("Marie", person.full_name);
+    assert_eq!(32, person.age);
+}
+
+#[test]
+pub fn initializers_should_be_evaluated_in_field_declaration_order() {
+    use std::cell::RefCell;
+    thread_local! {
+        static CALLS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+    }
+    fn record(name: &'static str, value: &u8) -> u8 {
+        CALLS.with(|calls| calls.borrow_mut().push(name));
+        *value
+    }
+    fn first(value: &u8) -> u8 {
+        record("first", value)
+    }
+    fn second(value: &u8) -> u8 {
+        record("second", value)
+    }
+    fn third(value: &u8) -> u8 {
+        record("third", value)
+    }
+    #[derive(Mapper)]
+    #[to(Scores)]
+    struct User {
+        #[to(Scores, field=c, with=first)]
+        a: u8,
+        #[to(Scores, field=a, with=second)]
+        b: u8,
+        #[to(Scores, field=b, with=third)]
+        c: u8,
+    }
+    #[derive(Debug, PartialEq)]
+    struct Scores {
+        a: u8,
+        b: u8,
+        c: u8,
+    }
+    let user = User { a: 1, b: 2, c: 3 };
+    let scores: Scores = user.to();
+    assert_eq!(Scores { a: 2, b: 3, c: 1 }, scores);
+    assert_eq!(
+        vec!["first", "second", "third"],
+        CALLS.with(|calls| calls.borrow().clone())
+    );
+}
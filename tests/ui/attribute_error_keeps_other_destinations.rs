@@ -0,0 +1,24 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, Customer)]
+struct User {
+    #[to(Person, feild = name)]
+    first_name: String,
+    name: String,
+}
+struct Person {
+    name: String,
+}
+struct Customer {
+    first_name: String,
+    name: String,
+}
+
+fn main() {
+    let user = User {
+        first_name: "John".to_string(),
+        name: "Doe".to_string(),
+    };
+    let _customer: Customer = user.to();
+}
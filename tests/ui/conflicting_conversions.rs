@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, boxed)]
+    #[to(Person, rc)]
+    age: u8,
+}
+struct Person {
+    age: Box<u8>,
+}
+
+fn main() {}
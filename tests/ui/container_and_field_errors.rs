@@ -0,0 +1,11 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[mapper(deny_unmaped)]
+#[to(Person)]
+struct User {
+    #[to(Person, feild = name)]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[mapper(deny_unmapped)]
+struct User {
+    #[to(Person, strategy=mapper)]
+    name: String,
+    email: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
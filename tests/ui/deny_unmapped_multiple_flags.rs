@@ -0,0 +1,12 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, deny_unmapped, deny_unmapped)]
+struct User {
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, field=name)]
+    first_name: String,
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
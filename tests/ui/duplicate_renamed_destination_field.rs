@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, rename_all = "lowercase")]
+struct User {
+    first_name: String,
+    firstname: String,
+}
+struct Person {
+    firstname: String,
+}
+
+fn main() {}
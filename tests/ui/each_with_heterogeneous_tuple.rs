@@ -0,0 +1,17 @@
+use mapper::Mapper;
+
+fn to_u16(value: &u8) -> u16 {
+    *value as u16
+}
+
+#[derive(Mapper)]
+#[to(Shape)]
+struct Point {
+    #[to(Shape, each(with=to_u16))]
+    color: (u8, u16, u8),
+}
+struct Shape {
+    color: (u16, u16, u16),
+}
+
+fn main() {}
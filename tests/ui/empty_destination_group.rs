@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[mapper(group(public = []))]
+#[to(Person)]
+struct User {
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
@@ -2,7 +2,7 @@ use mapper_impl::Mapper;
 
 #[derive(Mapper)]
 pub struct User{
-    #[to(Person, exclude, strategy=into)]
+    #[to(Person, exclude)]
     pub name: String
 }
 
@@ -0,0 +1,15 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    name: String,
+    #[to(Person, exclude(strategy=into))]
+    password: String,
+}
+struct Person {
+    name: String,
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, strategy=all)]
+struct User {
+    name: String,
+    #[to(Person, exclude, strategy=into)]
+    password: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
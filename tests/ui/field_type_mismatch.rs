@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    id: u64,
+    name: String,
+}
+struct Person {
+    id: u32,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, field = "first name")]
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+struct User {
+    #[to(Person, strategy=mapper)]
+    #[to(Person, exclude)]
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
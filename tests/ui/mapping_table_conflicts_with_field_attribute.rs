@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, map(name -> full_name))]
+struct User {
+    #[to(Person, field=first_name)]
+    name: String,
+}
+struct Person {
+    full_name: String,
+}
+
+fn main() {}
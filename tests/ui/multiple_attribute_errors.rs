@@ -0,0 +1,12 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, strategy = inot)]
+struct User {
+    #[to(Person, feild = name)]
+    id: u64,
+    #[to(Person, exlude)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use mapper::Mapper;
+
+struct Email(String);
+struct Login(String);
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, wrap_newtype=Email, wrap_newtype=Login)]
+    email: String,
+    #[to(Person, unwrap_newtype, unwrap_newtype)]
+    login: Login,
+    #[to(Person, direct, direct)]
+    age: u8,
+}
+struct Person {
+    email: Email,
+    login: String,
+    age: u8,
+}
+
+fn main() {}
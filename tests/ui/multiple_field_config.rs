@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, field=first_name, field=last_name)]
+    name: String,
+}
+struct Person {
+    first_name: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person, strip_prefix = "usr_")]
+struct UserRow {
+    usr_name: String,
+    email: String,
+}
+struct Person {
+    name: String,
+    email: String,
+}
+
+fn main() {}
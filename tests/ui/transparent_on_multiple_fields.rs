@@ -0,0 +1,7 @@
+use mapper_impl::Mapper;
+
+#[derive(Mapper)]
+#[to(String, transparent)]
+pub struct Email(String, u16);
+
+fn main(){}
@@ -0,0 +1,14 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[mapper(group(public = [Person]))]
+#[to(Person)]
+struct User {
+    #[to(publc, exclude)]
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use mapper::Mapper;
+
+fn to_label(value: &u8) -> String {
+    value.to_string()
+}
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, each(wiht(mapper) = to_label))]
+    ids: Vec<u8>,
+}
+struct Person {
+    ids: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, feild=full_name)]
+    name: String,
+}
+struct Person {
+    full_name: String,
+}
+
+fn main() {}
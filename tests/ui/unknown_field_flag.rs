@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    #[to(Person, field=full_name, boxd)]
+    name: String,
+}
+struct Person {
+    full_name: Box<String>,
+}
+
+fn main() {}
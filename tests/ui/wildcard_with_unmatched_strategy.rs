@@ -0,0 +1,17 @@
+use mapper::Mapper;
+
+fn to_label(value: &u8) -> String {
+    value.to_string()
+}
+
+#[derive(Mapper)]
+#[to(Person, strategy=into)]
+struct User {
+    #[to(*, with=to_label)]
+    age: u8,
+}
+struct Person {
+    age: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use mapper::Mapper;
+
+#[derive(Mapper)]
+struct User {
+    #[to(*, field=full_name)]
+    #[to(Person, strategy=mapper)]
+    name: String,
+}
+struct Person {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use mapper::Mapper;
+
+fn to_label(value: &u32) -> u64 {
+    *value as u64
+}
+
+#[derive(Mapper)]
+#[to(Person)]
+struct User {
+    name: String,
+    #[to(Person, with = to_label)]
+    label: u32,
+}
+struct Person {
+    name: String,
+    label: String,
+}
+
+fn main() {}
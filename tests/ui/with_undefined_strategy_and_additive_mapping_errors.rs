@@ -0,0 +1,23 @@
+use mapper_impl::Mapper;
+
+fn with_test(val: &String)->String{
+    val.clone()
+}
+
+#[derive(Mapper)]
+#[to(dto::Person,strategy=into)]
+pub struct User{
+    #[to(dto::Person, with=with_test)]
+    pub name: String,
+    #[to(dto::Person, strategy=into)]
+    pub age: u8
+}
+
+mod dto {
+    pub struct Person{
+        pub name: String,
+        pub age: u8
+    }
+}
+
+fn main(){}